@@ -1,10 +1,18 @@
 use dirs;
 use log::{debug, info};
-use std::{fs, path::PathBuf};
+use std::{
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+};
 use tracing::{error, span, Level};
 
 use crate::error::ConfigError;
 
+/// Environment variable that overrides the directory holding the database
+/// file (see `init_db_datafilepath`).
+pub const DATA_DIR_ENV: &str = "EVENT_PULSE_DATA_DIR";
+
 /// Checks and/or creates the local application data directory.
 ///
 /// This asynchronous function checks if the `$XDG_DATA_HOME` or `$HOME/.local/share/$dir_name`
@@ -39,8 +47,30 @@ use crate::error::ConfigError;
 /// ```
 pub async fn local_app_data_dir(dir_name: &str) -> Result<PathBuf, ConfigError> {
     let local_data_dir = dirs::data_local_dir().ok_or(ConfigError::LocalDataDirUnavailable)?;
-    let local_app_data_path = local_data_dir.join(dir_name);
+    local_app_data_dir_in(&local_data_dir, dir_name).await
+}
+
+/// Checks and/or creates the `dir_name` directory under `base`.
+///
+/// Like `local_app_data_dir`, but rooted at `base` instead of the platform data
+/// directory. Missing parent directories of `base` are created too.
+///
+/// # Arguments
+///
+/// * `base` - The directory to create `dir_name` in.
+/// * `dir_name` - A string slice containing the name of the directory to check or create.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the `PathBuf` of `base/dir_name` on success, or a
+/// `ConfigError::LocalAppDataDirCreationFailure` if it could not be created.
+pub async fn local_app_data_dir_in(base: &Path, dir_name: &str) -> Result<PathBuf, ConfigError> {
+    ensure_dir(base.join(dir_name))
+}
 
+/// Returns `local_app_data_path`, creating it and any missing parents first if
+/// it does not exist yet.
+fn ensure_dir(local_app_data_path: PathBuf) -> Result<PathBuf, ConfigError> {
     // Check if directory exists
     if local_app_data_path.exists() {
         let _span = span!(Level::INFO, "LocalDataDirCheck").entered();
@@ -51,8 +81,8 @@ pub async fn local_app_data_dir(dir_name: &str) -> Result<PathBuf, ConfigError>
         return Ok(local_app_data_path);
     }
 
-    // Attempt to create directory
-    if let Err(err) = fs::create_dir(&local_app_data_path) {
+    // Attempt to create directory, along with any missing parents
+    if let Err(err) = fs::create_dir_all(&local_app_data_path) {
         return Err(ConfigError::LocalAppDataDirCreationFailure(err.to_string()));
     }
 
@@ -70,6 +100,11 @@ pub async fn local_app_data_dir(dir_name: &str) -> Result<PathBuf, ConfigError>
 /// This function retrieves the local application data directory, constructs the
 /// database file path, and returns a PathBuf of the local data filepath.
 ///
+/// The directory defaults to the package's directory under the platform data
+/// directory (`local_app_data_dir`). Setting the `EVENT_PULSE_DATA_DIR`
+/// environment variable overrides it; the override directory is created,
+/// along with any missing parents, and holds the database file directly.
+///
 /// # Arguments
 ///
 /// * `db_filename` - A string slice containing the name of the database file.
@@ -101,11 +136,8 @@ pub async fn init_db_datafilepath(db_filename: &str) -> Result<PathBuf, ConfigEr
     // Start a new span for initialization
     let _span = span!(Level::INFO, "InitDatabaseFile").entered();
 
-    // Retrieve package name from Cargo manifest
-    let pkg_name = env!("CARGO_PKG_NAME");
-
-    // Obtain local app data directory
-    let data_dir = crate::config::local_app_data_dir(pkg_name)
+    // Obtain local app data directory, unless overridden
+    let data_dir = data_dir_for_override(std::env::var_os(DATA_DIR_ENV))
         .await
         .map_err(|e| {
             error!("Failed to obtain local app data directory: {}", e);
@@ -123,3 +155,56 @@ pub async fn init_db_datafilepath(db_filename: &str) -> Result<PathBuf, ConfigEr
 
     Ok(db_path)
 }
+
+/// Returns the data directory for a value of the `EVENT_PULSE_DATA_DIR`
+/// environment variable, creating it if needed.
+///
+/// A set, non-empty `value` is used as is; otherwise this falls back to the
+/// package's directory under the platform data directory.
+async fn data_dir_for_override(value: Option<OsString>) -> Result<PathBuf, ConfigError> {
+    match value {
+        Some(dir) if !dir.is_empty() => ensure_dir(PathBuf::from(dir)),
+        // Retrieve package name from Cargo manifest
+        _ => local_app_data_dir(env!("CARGO_PKG_NAME")).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("event-pulse-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_local_app_data_dir_in_creates_nested_path() {
+        let root = temp_dir("nested");
+        let base = root.join("a").join("b");
+        let path = futures::executor::block_on(local_app_data_dir_in(&base, "event-pulse"))
+            .expect("created directory");
+
+        assert_eq!(path, base.join("event-pulse"));
+        assert!(path.is_dir());
+        // A second call finds the existing directory.
+        assert_eq!(
+            futures::executor::block_on(local_app_data_dir_in(&base, "event-pulse")).unwrap(),
+            path
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_data_dir_for_override_creates_override() {
+        let root = temp_dir("override");
+        let dir = root.join("data").join("db");
+        let data_dir =
+            futures::executor::block_on(data_dir_for_override(Some(dir.clone().into_os_string())));
+
+        assert_eq!(data_dir.unwrap(), dir);
+        assert!(dir.is_dir());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -34,6 +34,53 @@ pub enum AppError {
 
     #[error("Parse error: {0}")]
     ParseError(String),
+
+    #[error("Parse error: {0}")]
+    TimeParseError(#[from] crate::models::time::TimeParseError),
+
+    #[error("Arithmetic overflow: {0}")]
+    Overflow(String),
+
+    #[error("Notification error: {0}")]
+    NotificationError(String),
+
+    #[error("Configuration error: {0}")]
+    ConfigError(String),
+}
+
+impl From<ConfigError> for AppError {
+    fn from(err: ConfigError) -> Self {
+        AppError::ConfigError(err.to_string())
+    }
+}
+
+/// Defines notification delivery error types.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum NotifyError {
+    /// No transport is configured for the channel.
+    #[error("No transport configured for channel: {0}")]
+    NoTransport(String),
+
+    /// The transport failed to deliver a message to the recipient.
+    #[error("Failed to deliver to {recipient}: {reason}")]
+    DeliveryFailed { recipient: String, reason: String },
+
+    /// The recipient, redacted, cannot be delivered to through the channel.
+    #[error("{recipient} is not a valid {channel} recipient")]
+    InvalidRecipient {
+        recipient: String,
+        channel: &'static str,
+    },
+
+    /// Adding recipients would exceed the notification's recipient cap.
+    #[error("Notification is capped at {max} recipients")]
+    TooManyRecipients { max: usize },
+}
+
+impl From<NotifyError> for AppError {
+    fn from(err: NotifyError) -> Self {
+        AppError::NotificationError(err.to_string())
+    }
 }
 
 /// Defines configuration setting error types.
@@ -0,0 +1,357 @@
+use std::fmt::Write;
+
+use crate::models::{event::Event, notify::EventNotify};
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+
+/// A collection of events that can be queried by their upcoming occurrences.
+///
+/// # Examples
+///
+/// ```
+/// use event_pulse::models::calendar::Calendar;
+///
+/// let calendar = Calendar::new();
+/// assert!(calendar.events().is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Calendar {
+    events: Vec<Event>,
+}
+
+impl Calendar {
+    /// Creates an empty `Calendar`.
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Adds an event to the calendar.
+    pub fn add_event(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    /// Adds an event to the calendar unless it has already ended at `now`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the event was added, `false` if it was skipped as expired.
+    pub fn add_if_active(&mut self, event: Event, now: DateTime<Utc>) -> bool {
+        if event.is_expired(now) {
+            return false;
+        }
+        self.add_event(event);
+        true
+    }
+
+    /// Exports every event as an iCalendar file (RFC 5545): one `VEVENT` per
+    /// event inside a single `VCALENDAR`, with lines separated by CRLF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::calendar::Calendar;
+    ///
+    /// let ics = Calendar::new().to_ics();
+    /// assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    /// assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    /// ```
+    pub fn to_ics(&self) -> String {
+        let mut ics =
+            String::from("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//event-pulse//EN\r\n");
+        for event in &self.events {
+            ics.push_str(&event.to_vevent());
+            ics.push_str("\r\n");
+        }
+        ics.push_str("END:VCALENDAR\r\n");
+        ics
+    }
+
+    /// Returns the events held by the calendar.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Returns the event whose next occurrence strictly after `after` is the
+    /// soonest, along with that occurrence.
+    ///
+    /// Ties are broken in favour of the event created first. Returns `None` if
+    /// no event has an occurrence left.
+    pub fn next_event(&self, after: DateTime<Utc>) -> Option<(&Event, DateTime<Utc>)> {
+        self.events
+            .iter()
+            .filter_map(|event| {
+                event
+                    .next_occurrence(after)
+                    .map(|occurrence| (event, occurrence))
+            })
+            .min_by(|(a, a_next), (b, b_next)| {
+                a_next
+                    .cmp(b_next)
+                    .then_with(|| a.created_at().cmp(&b.created_at()))
+            })
+    }
+
+    /// Returns the events with an occurrence on `date`, along with that
+    /// occurrence.
+    ///
+    /// The date is interpreted in UTC, i.e. as the span from 00:00:00 UTC up to
+    /// the following midnight.
+    pub fn events_on(&self, date: NaiveDate) -> Vec<(&Event, DateTime<Utc>)> {
+        let day_start = date.and_time(NaiveTime::MIN).and_utc();
+        let day_end = day_start + Duration::try_days(1).expect("1 day");
+        self.events
+            .iter()
+            .filter_map(|event| {
+                event
+                    .next_occurrence(day_start - Duration::nanoseconds(1))
+                    .filter(|occurrence| *occurrence < day_end)
+                    .map(|occurrence| (event, occurrence))
+            })
+            .collect()
+    }
+}
+
+/// Renders the calendar and its notifications as a readable, multi-line
+/// string for logs and support tickets.
+///
+/// Each event is listed with a summary line and its next fire after `now`,
+/// followed by the notifications scheduled for it and their next send.
+/// Recipients are never printed, only counted. Notifications whose event is not
+/// in the calendar are listed at the end.
+///
+/// # Arguments
+///
+/// * `calendar` - The calendar to dump.
+/// * `notifies` - The notifications to list alongside their events.
+/// * `now` - The reference point for next fires and next sends.
+pub fn debug_dump(calendar: &Calendar, notifies: &[EventNotify], now: DateTime<Utc>) -> String {
+    let or_none = |datetime: Option<DateTime<Utc>>| {
+        datetime.map_or_else(|| "none".to_string(), |datetime| datetime.to_rfc3339())
+    };
+    let mut dump = String::new();
+    writeln!(
+        dump,
+        "calendar at {}: {} event(s), {} notification(s)",
+        now.to_rfc3339(),
+        calendar.events().len(),
+        notifies.len()
+    )
+    .expect("writing to a String cannot fail");
+
+    let mut listed = vec![false; notifies.len()];
+    for event in calendar.events() {
+        writeln!(
+            dump,
+            "- {} [{}] {} from {} to {}",
+            event.title,
+            event.epoch,
+            event.amount,
+            event.start_datetime.to_rfc3339(),
+            event.end_datetime.to_rfc3339()
+        )
+        .expect("writing to a String cannot fail");
+        writeln!(dump, "  next fire: {}", or_none(event.next_occurrence(now)))
+            .expect("writing to a String cannot fail");
+
+        for (index, notify) in notifies.iter().enumerate() {
+            if notify.event_id() == event.id() {
+                listed[index] = true;
+                writeln!(
+                    dump,
+                    "  notify {}: next send {}",
+                    notify.delivery_summary(),
+                    or_none(notify.next_send(now))
+                )
+                .expect("writing to a String cannot fail");
+            }
+        }
+    }
+
+    for (notify, _) in notifies.iter().zip(listed).filter(|(_, listed)| !listed) {
+        writeln!(
+            dump,
+            "- unknown event: notify {}: next send {}",
+            notify.delivery_summary(),
+            or_none(notify.next_send(now))
+        )
+        .expect("writing to a String cannot fail");
+    }
+
+    dump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::notify::{Recipient, SendFrequency, TransportMethod};
+    use crate::models::{decimal::Money, CalendarData, Epoch, SignalTrigger};
+    use chrono::TimeZone;
+
+    fn event(title: &str, epoch: Epoch, start: DateTime<Utc>) -> Event {
+        Event::new(
+            title.to_string(),
+            Money::new(10, 0),
+            epoch,
+            None,
+            SignalTrigger::from_str("M09:00:00::I86400").expect("valid signal trigger"),
+            start,
+            Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_next_event_returns_soonest() {
+        let mut calendar = Calendar::new();
+        calendar.add_event(event(
+            "monthly",
+            Epoch::Month(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+        ));
+        calendar.add_event(event(
+            "weekly",
+            Epoch::Week(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2024, 1, 3, 9, 0, 0).unwrap(),
+        ));
+        calendar.add_event(event(
+            "yearly",
+            Epoch::Year(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2023, 3, 1, 8, 0, 0).unwrap(),
+        ));
+
+        let after = Utc.with_ymd_and_hms(2024, 2, 5, 0, 0, 0).unwrap();
+        let (event, next) = calendar.next_event(after).expect("an upcoming event");
+        assert_eq!(event.title, "weekly");
+        assert_eq!(next, Utc.with_ymd_and_hms(2024, 2, 7, 9, 0, 0).unwrap());
+
+        let after = Utc.with_ymd_and_hms(2024, 2, 28, 10, 0, 0).unwrap();
+        let (event, next) = calendar.next_event(after).expect("an upcoming event");
+        assert_eq!(event.title, "yearly");
+        assert_eq!(next, Utc.with_ymd_and_hms(2024, 3, 1, 8, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_events_on_weekly_weekday() {
+        let mut calendar = Calendar::new();
+        calendar.add_event(event(
+            "weekly",
+            Epoch::Week(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2024, 3, 4, 9, 0, 0).unwrap(), // Monday
+        ));
+        calendar.add_event(event(
+            "monthly",
+            Epoch::Month(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap(),
+        ));
+
+        let monday = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+        let events = calendar.events_on(monday);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0.title, "weekly");
+        assert_eq!(
+            events[0].1,
+            Utc.with_ymd_and_hms(2024, 3, 11, 9, 0, 0).unwrap()
+        );
+
+        let tuesday = NaiveDate::from_ymd_opt(2024, 3, 12).unwrap();
+        assert!(calendar.events_on(tuesday).is_empty());
+
+        let midnight = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let events = calendar.events_on(midnight);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0.title, "monthly");
+    }
+
+    #[test]
+    fn test_debug_dump_lists_events_and_notifications() {
+        let mut calendar = Calendar::new();
+        let weekly = event(
+            "Gym",
+            Epoch::Week(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2024, 3, 4, 9, 0, 0).unwrap(),
+        );
+        calendar.add_event(weekly.clone());
+        calendar.add_event(event(
+            "Rent",
+            Epoch::Month(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        ));
+        let recipient = Recipient::new("jane@example.com");
+        let notify = EventNotify::new(
+            weekly,
+            TransportMethod::Email(recipient.clone()),
+            SendFrequency::OnTrigger,
+            vec![recipient],
+            SignalTrigger::from_str("M08:00:00::I86400").expect("valid signal trigger"),
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+        )
+        .expect("valid recipients");
+
+        let now = Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap();
+        let dump = debug_dump(&calendar, &[notify], now);
+
+        assert!(dump.contains("- Gym [1w1x]"));
+        assert!(dump.contains("- Rent [1m1x]"));
+        assert!(dump.contains("next fire: 2024-03-11T09:00:00+00:00"));
+        assert!(dump.contains("next fire: 2024-04-01T00:00:00+00:00"));
+        assert!(dump.contains("notify email OnTrigger, 1 recipient(s): next send"));
+        assert!(!dump.contains("jane@example.com"));
+    }
+
+    #[test]
+    fn test_add_if_active_skips_expired_events() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut calendar = Calendar::new();
+        let mut expired = event(
+            "expired",
+            Epoch::Month(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+        );
+        expired.end_datetime = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let active = event(
+            "active",
+            Epoch::Month(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        );
+
+        assert!(!calendar.add_if_active(expired, now));
+        assert!(calendar.add_if_active(active, now));
+        assert_eq!(calendar.events().len(), 1);
+        assert_eq!(calendar.events()[0].title, "active");
+    }
+
+    #[test]
+    fn test_to_ics_wraps_every_event() {
+        let mut calendar = Calendar::new();
+        let gym = event(
+            "Gym",
+            Epoch::Week(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2024, 3, 4, 9, 0, 0).unwrap(),
+        );
+        let rent = event(
+            "Rent",
+            Epoch::Month(CalendarData::new(1, 1)),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        );
+        let uids = [
+            format!("UID:{}@event-pulse", gym.global_id()),
+            format!("UID:{}@event-pulse", rent.global_id()),
+        ];
+        calendar.add_event(gym);
+        calendar.add_event(rent);
+
+        let ics = calendar.to_ics();
+        assert_eq!(ics.matches("BEGIN:VCALENDAR").count(), 1);
+        assert_eq!(ics.matches("END:VCALENDAR").count(), 1);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert_eq!(ics.matches("END:VEVENT").count(), 2);
+        assert!(ics.contains("PRODID:"));
+        assert!(uids.iter().all(|uid| ics.contains(uid.as_str())));
+        assert_ne!(uids[0], uids[1]);
+    }
+
+    #[test]
+    fn test_next_event_empty_calendar() {
+        let calendar = Calendar::new();
+        assert_eq!(calendar.next_event(Utc::now()), None);
+    }
+}
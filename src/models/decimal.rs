@@ -1,5 +1,5 @@
-use rust_decimal::Decimal as RustDecimal;
-use std::fmt;
+use rust_decimal::{Decimal as RustDecimal, RoundingStrategy};
+use std::{fmt, str::FromStr};
 use structsy::derive::PersistentEmbedded;
 use thiserror::Error;
 
@@ -9,6 +9,136 @@ pub enum MoneyError {
     /// Indicates the i64 value cannot be represented because it overflows.
     #[error("Value cannot be represented as i64")]
     ValueOverflow,
+    /// Indicates the string is not a valid monetary amount.
+    #[error("Invalid money format: {0}")]
+    InvalidFormat(String),
+    /// Indicates the ISO 4217 currency code is not supported.
+    #[error("Unknown currency code: {0}")]
+    UnknownCurrency(String),
+    /// Indicates the currency code is known but not accepted where it was used,
+    /// e.g. a EUR amount parsed as a USD `Money`.
+    #[error("Unsupported currency code: {0}")]
+    UnsupportedCurrency(String),
+}
+
+/// ISO 4217 currencies supported when parsing `Money` amounts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Currency {
+    Usd,
+    Eur,
+    Gbp,
+    Jpy,
+    Cad,
+    Aud,
+    Chf,
+}
+
+impl Currency {
+    /// Returns the ISO 4217 code of the currency.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Usd => "USD",
+            Self::Eur => "EUR",
+            Self::Gbp => "GBP",
+            Self::Jpy => "JPY",
+            Self::Cad => "CAD",
+            Self::Aud => "AUD",
+            Self::Chf => "CHF",
+        }
+    }
+
+    /// Returns the number of decimal digits of the currency's minor unit.
+    pub fn minor_units(&self) -> u32 {
+        match self {
+            Self::Jpy => 0,
+            _ => 2,
+        }
+    }
+}
+
+impl FromStr for Currency {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Currency, MoneyError> {
+        match s {
+            "USD" => Ok(Self::Usd),
+            "EUR" => Ok(Self::Eur),
+            "GBP" => Ok(Self::Gbp),
+            "JPY" => Ok(Self::Jpy),
+            "CAD" => Ok(Self::Cad),
+            "AUD" => Ok(Self::Aud),
+            "CHF" => Ok(Self::Chf),
+            _ => Err(MoneyError::UnknownCurrency(s.to_string())),
+        }
+    }
+}
+
+/// Where the currency symbol goes relative to the amount.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolPosition {
+    /// Before the amount, e.g. `$1.00`.
+    Prefix,
+    /// After the amount, separated by a space, e.g. `1,00 €`.
+    Suffix,
+}
+
+/// Describes how `Money::format_with` renders an amount: the currency symbol
+/// and its position, and the grouping and decimal separators.
+///
+/// # Examples
+///
+/// ```
+/// use event_pulse::models::decimal::{Money, MoneyFormat};
+///
+/// assert_eq!(Money::new(1234, 56).format_with(&MoneyFormat::eur()), "1.234,56 €");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoneyFormat {
+    /// The currency symbol; an empty symbol renders the bare amount.
+    pub symbol: String,
+    pub symbol_position: SymbolPosition,
+    /// Separates groups of three digits in the whole part, if any.
+    pub grouping_separator: Option<char>,
+    pub decimal_separator: char,
+}
+
+impl MoneyFormat {
+    /// US dollars, e.g. `-$1,234.56`, the format used by `Display`.
+    pub fn usd() -> Self {
+        Self {
+            symbol: "$".to_string(),
+            symbol_position: SymbolPosition::Prefix,
+            grouping_separator: Some(','),
+            decimal_separator: '.',
+        }
+    }
+
+    /// Euros in the continental European style, e.g. `-1.234,56 €`.
+    pub fn eur() -> Self {
+        Self {
+            symbol: "€".to_string(),
+            symbol_position: SymbolPosition::Suffix,
+            grouping_separator: Some('.'),
+            decimal_separator: ',',
+        }
+    }
+}
+
+impl Default for MoneyFormat {
+    fn default() -> Self {
+        Self::usd()
+    }
+}
+
+/// Rounding strategies applied when dropping precision from a `Money` amount.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, with halves rounded away from zero.
+    HalfUp,
+    /// Rounds toward zero (truncation).
+    Down,
+    /// Rounds away from zero.
+    Up,
 }
 
 /// Represents a monetary amount consisting of a whole part and a fractional part.
@@ -23,7 +153,7 @@ pub enum MoneyError {
 ///     fractional: 50,
 /// };
 /// ```
-#[derive(Clone, Debug, PartialEq, PersistentEmbedded)]
+#[derive(Clone, Debug, PartialEq, Eq, PersistentEmbedded)]
 pub struct Money {
     pub whole: i64,
     pub fractional: i64,
@@ -54,10 +184,167 @@ impl Money {
         Self { whole, fractional }
     }
 
+    /// Constructs a `Money` instance from an amount expressed in hundredths
+    /// (cents). Both parts carry the sign of `cents`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::Money;
+    ///
+    /// assert_eq!(Money::from_cents(1099), Money::new(10, 99));
+    /// assert_eq!(Money::from_cents(-1099), Money::new(-10, -99));
+    /// ```
+    pub fn from_cents(cents: i64) -> Self {
+        Self::new(cents / 100, cents % 100)
+    }
+
+    /// Parses an amount followed by an optional ISO 4217 currency code, e.g.
+    /// `"1234.56 EUR"`. A bare amount defaults to `Currency::Usd`.
+    ///
+    /// The amount accepts an optional leading `-` and up to two fractional
+    /// digits; currencies without a minor unit, such as JPY, only accept a zero
+    /// fractional part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::{Currency, Money};
+    ///
+    /// let (money, currency) = Money::parse_with_currency("9.99 EUR").unwrap();
+    /// assert_eq!(money, Money::new(9, 99));
+    /// assert_eq!(currency, Currency::Eur);
+    /// assert!(Money::parse_with_currency("9.99 XXX").is_err());
+    /// ```
+    pub fn parse_with_currency(s: &str) -> Result<(Money, Currency), MoneyError> {
+        let s = s.trim();
+        let (amount, currency) = match s.rsplit_once(' ') {
+            Some((amount, code)) => (amount.trim_end(), code.parse::<Currency>()?),
+            None => (s, Currency::Usd),
+        };
+
+        let money = parse_amount(amount)?;
+        if currency.minor_units() == 0 && money.fractional != 0 {
+            return Err(MoneyError::InvalidFormat(format!(
+                "{} does not have fractional units: {}",
+                currency.code(),
+                s
+            )));
+        }
+        Ok((money, currency))
+    }
+
+    /// Adds two amounts, carrying fractional overflow into the whole part.
+    ///
+    /// The result is normalized so that `whole` and `fractional` share the sign
+    /// of the total.
+    ///
+    /// # Returns
+    ///
+    /// The sum, or `MoneyError::ValueOverflow` if the whole part does not fit in
+    /// an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::Money;
+    ///
+    /// let sum = Money::new(10, 60).checked_add(&Money::new(0, 50)).unwrap();
+    /// assert_eq!(sum, Money::new(11, 10));
+    /// assert!(Money::new(i64::MAX, 0).checked_add(&Money::new(1, 0)).is_err());
+    /// ```
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        Money::from_i128_cents(self.i128_cents() + other.i128_cents())
+    }
+
+    /// Subtracts `other` from this amount, borrowing from the whole part when
+    /// the fractional part runs short.
+    ///
+    /// # Returns
+    ///
+    /// The difference, or `MoneyError::ValueOverflow` if the whole part does not
+    /// fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::Money;
+    ///
+    /// let difference = Money::new(11, 0).checked_sub(&Money::new(0, 1)).unwrap();
+    /// assert_eq!(difference, Money::new(10, 99));
+    /// ```
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        Money::from_i128_cents(self.i128_cents() - other.i128_cents())
+    }
+
+    /// Negates the amount, returning `MoneyError::ValueOverflow` if the result
+    /// does not fit in an `i64` (only possible for `i64::MIN` whole parts).
+    pub fn checked_neg(&self) -> Result<Money, MoneyError> {
+        Money::from_i128_cents(-self.i128_cents())
+    }
+
+    /// Returns the amount in cents, widened so that it cannot overflow.
+    fn i128_cents(&self) -> i128 {
+        self.whole as i128 * 100 + self.fractional as i128
+    }
+
+    /// Builds a normalized `Money` from an amount in cents, with both parts
+    /// carrying the sign of `cents`.
+    fn from_i128_cents(cents: i128) -> Result<Money, MoneyError> {
+        let whole = i64::try_from(cents / 100).map_err(|_| MoneyError::ValueOverflow)?;
+        Ok(Money::new(whole, (cents % 100) as i64))
+    }
+
+    /// Returns the monetary amount expressed in hundredths (cents).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount does not fit in an `i64` of cents, e.g. for
+    /// `Money::new(i64::MAX, 0)`; use `checked_total_cents` or
+    /// `saturating_total_cents` when the parts are not known to be in range.
+    pub fn total_cents(&self) -> i64 {
+        self.checked_total_cents()
+            .expect("Money::total_cents overflowed i64")
+    }
+
+    /// Returns the monetary amount expressed in hundredths (cents).
+    ///
+    /// # Returns
+    ///
+    /// The amount in cents, or `MoneyError::ValueOverflow` if it does not fit in
+    /// an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::{Money, MoneyError};
+    ///
+    /// assert_eq!(Money::new(10, 99).checked_total_cents(), Ok(1099));
+    /// assert_eq!(
+    ///     Money::new(i64::MAX, i64::MAX).checked_total_cents(),
+    ///     Err(MoneyError::ValueOverflow)
+    /// );
+    /// ```
+    pub fn checked_total_cents(&self) -> Result<i64, MoneyError> {
+        self.whole
+            .checked_mul(100)
+            .and_then(|cents| cents.checked_add(self.fractional))
+            .ok_or(MoneyError::ValueOverflow)
+    }
+
+    /// Returns the monetary amount expressed in hundredths (cents), clamped to
+    /// `i64::MIN..=i64::MAX`, for display paths and estimates that must not
+    /// fail.
+    pub fn saturating_total_cents(&self) -> i64 {
+        self.i128_cents().clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
     /// Constructs a `Money` instance from a `RustDecimal`.
     ///
-    /// This method extracts the integral part and scale from the provided `RustDecimal`.
-    /// If the scale is greater than 28, it normalizes it to 28 by adjusting the integral part.
+    /// The fractional part is always expressed in hundredths, whatever the
+    /// scale of `decimal`: `1.5` becomes `Money::new(1, 50)`. Digits beyond the
+    /// second decimal place are truncated toward zero; use
+    /// `from_rust_decimal_rounded` to round them instead.
     ///
     /// # Arguments
     ///
@@ -65,70 +352,449 @@ impl Money {
     ///
     /// # Returns
     ///
-    /// A new `Money` instance representing the monetary amount, or an error if the scale is too
-    /// large or if the value cannot be represented as `i64`.
+    /// A new `Money` instance representing the monetary amount, or an error if the value
+    /// cannot be represented as `i64`.
     ///
     /// # Errors
     ///
-    /// This method may return an error of type `MoneyError` or panic under the following conditions:
+    /// This method returns `MoneyError::ValueOverflow` if the whole part cannot be
+    /// represented as `i64`, indicating that the monetary amount is too large to fit
+    /// within the range of a 64-bit signed integer.
     ///
-    /// * If the scale of the provided `RustDecimal` is greater than 28, indicating that the scale
-    /// is too large to represent a monetary amount accurately.
+    /// # Examples
     ///
-    /// * If the adjusted integral part cannot be represented as `i64`, indicating that the monetary
-    /// amount is too large to fit within the range of a 64-bit signed integer.
+    /// ```
+    /// use event_pulse::models::decimal::Money;
+    /// use rust_decimal::Decimal;
+    ///
+    /// assert_eq!(Money::from_rust_decimal(Decimal::new(15, 1)), Ok(Money::new(1, 50)));
+    /// assert_eq!(Money::from_rust_decimal(Decimal::new(2125, 3)), Ok(Money::new(2, 12)));
+    /// ```
     pub fn from_rust_decimal(decimal: RustDecimal) -> Result<Self, MoneyError> {
-        // Extracting integral part and scale
-        let (integral_part, scale) = (decimal.mantissa(), decimal.scale());
+        Money::from_rust_decimal_rounded(decimal, RoundingMode::Down)
+    }
 
-        // Adjusting the integral part based on the scale.
-        // If the adjusted_integral part is too large for i64 and overflows it will panic
-        // by rust_decimal.  We do not need to worry about error handling here in that case.
-        let adjusted_integral = integral_part / 10_i128.pow(scale as u32);
+    /// Constructs a `Money` instance from a `RustDecimal`, rounding it to cents
+    /// with `mode` instead of truncating like `from_rust_decimal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `decimal` - The `RustDecimal` from which to construct the `Money` instance.
+    /// * `mode` - How to round digits beyond the second decimal place.
+    ///
+    /// # Returns
+    ///
+    /// The rounded amount, or `MoneyError::ValueOverflow` if the whole part
+    /// cannot be represented as `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::{Money, RoundingMode};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let decimal = Decimal::new(3145, 3); // 3.145
+    /// assert_eq!(Money::from_rust_decimal_rounded(decimal, RoundingMode::HalfUp), Ok(Money::new(3, 15)));
+    /// assert_eq!(Money::from_rust_decimal_rounded(decimal, RoundingMode::Down), Ok(Money::new(3, 14)));
+    /// ```
+    pub fn from_rust_decimal_rounded(
+        decimal: RustDecimal,
+        mode: RoundingMode,
+    ) -> Result<Self, MoneyError> {
+        let strategy = match mode {
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::Down => RoundingStrategy::ToZero,
+            RoundingMode::Up => RoundingStrategy::AwayFromZero,
+        };
+        let rounded = decimal.round_dp_with_strategy(2, strategy);
 
-        // Check if adjusted_integral fits within the range of i64
-        if adjusted_integral > i64::MAX as i128 || adjusted_integral < i64::MIN as i128 {
+        // After rounding the scale is at most 2, so the mantissa scales up to cents.
+        let cents = rounded.mantissa() * 10_i128.pow(2 - rounded.scale());
+        let whole = cents / 100;
+        if whole > i64::MAX as i128 || whole < i64::MIN as i128 {
             return Err(MoneyError::ValueOverflow);
         }
 
-        // Convert the adjusted integral part to whole and fractional parts
-        let whole = adjusted_integral as i64;
-        let fractional = (integral_part % 10_i128.pow(scale as u32)) as i64;
+        Ok(Self {
+            whole: whole as i64,
+            fractional: (cents % 100) as i64,
+        })
+    }
+
+    /// Converts the amount to whole dollars, rounding the cents with `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::{Money, RoundingMode};
+    ///
+    /// let money = Money::new(10, 50);
+    /// assert_eq!(money.to_whole_dollars_rounded(RoundingMode::HalfUp), 11);
+    /// assert_eq!(money.to_whole_dollars_rounded(RoundingMode::Down), 10);
+    /// ```
+    pub fn to_whole_dollars_rounded(&self, mode: RoundingMode) -> i64 {
+        let cents = self.saturating_total_cents();
+        let (dollars, remainder) = (cents / 100, cents % 100);
+        match mode {
+            RoundingMode::Down => dollars,
+            RoundingMode::Up => dollars + remainder.signum(),
+            RoundingMode::HalfUp if remainder.abs() >= 50 => dollars + remainder.signum(),
+            RoundingMode::HalfUp => dollars,
+        }
+    }
+
+    /// Renders the amount as spoken English, e.g. for screen readers and voice
+    /// integrations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::Money;
+    ///
+    /// let money = Money::new(10, 99);
+    /// assert_eq!(money.to_words(), "ten dollars and ninety-nine cents");
+    /// ```
+    pub fn to_words(&self) -> String {
+        let cents = self.saturating_total_cents();
+        let dollars = cents.unsigned_abs() / 100;
+        let fractional = cents.unsigned_abs() % 100;
+
+        format!(
+            "{}{} {} and {} {}",
+            if cents < 0 { "negative " } else { "" },
+            number_to_words(dollars),
+            if dollars == 1 { "dollar" } else { "dollars" },
+            number_to_words(fractional),
+            if fractional == 1 { "cent" } else { "cents" },
+        )
+    }
+}
+
+impl Money {
+    /// Formats the amount with the symbol and separators of `fmt`.
+    ///
+    /// The amount is normalized through its total value in cents first, so
+    /// `Money::new(-1, 50)` renders as `-$0.50` and `Money::new(0, 150)` as
+    /// `$1.50`. The sign precedes the whole formatted amount, symbol included
+    /// (`-$0.50`, `-0,50 €`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::{Money, MoneyFormat, SymbolPosition};
+    ///
+    /// let plain = MoneyFormat {
+    ///     symbol: String::new(),
+    ///     symbol_position: SymbolPosition::Prefix,
+    ///     grouping_separator: None,
+    ///     decimal_separator: '.',
+    /// };
+    /// assert_eq!(Money::new(-1234, -5).format_with(&plain), "-1234.05");
+    /// ```
+    pub fn format_with(&self, fmt: &MoneyFormat) -> String {
+        let cents = self.i128_cents();
+        let whole = (cents.unsigned_abs() / 100).to_string();
+        let fractional = cents.unsigned_abs() % 100;
+
+        // Group the whole part in threes, counting from the right
+        let mut grouped = String::with_capacity(whole.len() + whole.len() / 3);
+        for (i, digit) in whole.chars().enumerate() {
+            if let Some(separator) = fmt.grouping_separator {
+                if i > 0 && (whole.len() - i).is_multiple_of(3) {
+                    grouped.push(separator);
+                }
+            }
+            grouped.push(digit);
+        }
 
-        Ok(Self { whole, fractional })
+        let sign = if cents < 0 { "-" } else { "" };
+        let amount = format!("{}{}{:02}", grouped, fmt.decimal_separator, fractional);
+        match (fmt.symbol.is_empty(), fmt.symbol_position) {
+            (true, _) => format!("{}{}", sign, amount),
+            (false, SymbolPosition::Prefix) => format!("{}{}{}", sign, fmt.symbol, amount),
+            (false, SymbolPosition::Suffix) => format!("{}{} {}", sign, amount, fmt.symbol),
+        }
+    }
+}
+
+impl FromStr for Money {
+    type Err = MoneyError;
+
+    /// Parses a US dollar amount, optionally followed by the `USD` currency code.
+    ///
+    /// The amount may start with a `$` sign, use `,` as a thousands separator
+    /// and have up to two fractional digits, so the output of `Display` parses
+    /// back to the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::Money;
+    ///
+    /// assert_eq!("$1,234.50".parse::<Money>(), Ok(Money::new(1234, 50)));
+    /// assert_eq!("10.5".parse::<Money>(), Ok(Money::new(10, 50)));
+    /// assert!("10.505".parse::<Money>().is_err());
+    /// ```
+    ///
+    /// Negatives may use a leading minus or, as in accounting exports, be
+    /// wrapped in parentheses:
+    ///
+    /// ```
+    /// use event_pulse::models::decimal::Money;
+    ///
+    /// assert_eq!("($123.45)".parse::<Money>(), Ok(Money::new(-123, -45)));
+    /// assert_eq!("-$123.45".parse::<Money>(), Ok(Money::new(-123, -45)));
+    /// ```
+    ///
+    /// Use [`Money::parse_with_currency`] to accept other currencies; here they
+    /// are rejected with `MoneyError::UnsupportedCurrency`.
+    fn from_str(s: &str) -> Result<Money, MoneyError> {
+        match Money::parse_with_currency(s)? {
+            (money, Currency::Usd) => Ok(money),
+            (_, currency) => Err(MoneyError::UnsupportedCurrency(currency.code().to_string())),
+        }
+    }
+}
+
+/// Parses a plain decimal amount such as `"-12.5"` or `"($12.50)"` into
+/// `Money`, with the fractional part scaled to hundredths.
+fn parse_amount(s: &str) -> Result<Money, MoneyError> {
+    let invalid = || MoneyError::InvalidFormat(s.to_string());
+    let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+
+    // Accounting exports wrap negatives in parentheses, e.g. `($123.45)`.
+    let (parenthesized, signed) = match s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        Some(inner) => (true, inner),
+        None => (false, s),
+    };
+
+    // Both `-$10.00`, as produced by `Display`, and `$-10.00` are accepted.
+    let (negative, unsigned) = match signed.strip_prefix('-') {
+        Some(rest) => (true, rest.strip_prefix('$').unwrap_or(rest)),
+        None => match signed.strip_prefix('$') {
+            Some(rest) => match rest.strip_prefix('-') {
+                Some(rest) => (true, rest),
+                None => (false, rest),
+            },
+            None => (false, signed),
+        },
+    };
+    if parenthesized && negative {
+        return Err(invalid());
+    }
+    let negative = negative || parenthesized;
+    let (whole, fractional) = match unsigned.split_once('.') {
+        Some((whole, fractional)) => (whole, fractional),
+        None => (unsigned, "00"),
+    };
+    if !is_digits(fractional) || fractional.len() > 2 {
+        return Err(invalid());
+    }
+
+    // Thousands separators must split the whole part into groups of three.
+    let mut groups = whole.split(',');
+    let leading = groups.next().unwrap_or_default();
+    let grouped = whole.contains(',');
+    if !is_digits(leading)
+        || (grouped && leading.len() > 3)
+        || !groups.all(|group| group.len() == 3 && is_digits(group))
+    {
+        return Err(invalid());
+    }
+
+    let whole = whole
+        .replace(',', "")
+        .parse::<i64>()
+        .map_err(|_| MoneyError::ValueOverflow)?;
+    let fractional = format!("{:0<2}", fractional)
+        .parse::<i64>()
+        .map_err(|_| invalid())?;
+    Ok(if negative {
+        Money::new(-whole, -fractional)
+    } else {
+        Money::new(whole, fractional)
+    })
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const SCALES: [&str; 7] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+];
+
+/// Converts a number into English words, e.g. `1234` into
+/// "one thousand two hundred thirty-four".
+fn number_to_words(number: u64) -> String {
+    if number == 0 {
+        return ONES[0].to_string();
+    }
+
+    let mut groups = Vec::new();
+    let mut remaining = number;
+    let mut scale = 0;
+    while remaining > 0 {
+        let group = remaining % 1000;
+        if group > 0 {
+            let words = hundreds_to_words(group);
+            groups.push(match SCALES[scale] {
+                "" => words,
+                scale_name => format!("{} {}", words, scale_name),
+            });
+        }
+        remaining /= 1000;
+        scale += 1;
+    }
+    groups.reverse();
+    groups.join(" ")
+}
+
+/// Converts a number below one thousand into English words.
+fn hundreds_to_words(number: u64) -> String {
+    let hundreds = (number / 100) as usize;
+    let rest = (number % 100) as usize;
+
+    let mut words = Vec::new();
+    if hundreds > 0 {
+        words.push(format!("{} hundred", ONES[hundreds]));
+    }
+    if rest >= 20 {
+        words.push(match rest % 10 {
+            0 => TENS[rest / 10].to_string(),
+            ones => format!("{}-{}", TENS[rest / 10], ONES[ones]),
+        });
+    } else if rest > 0 {
+        words.push(ONES[rest].to_string());
+    }
+    words.join(" ")
+}
+
+impl std::ops::Add for Money {
+    type Output = Money;
+
+    /// Adds two amounts with fractional carry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows, like integer addition; use
+    /// `Money::checked_add` to handle that case.
+    fn add(self, other: Money) -> Money {
+        self.checked_add(&other)
+            .expect("attempt to add Money with overflow")
+    }
+}
+
+impl std::ops::Sub for Money {
+    type Output = Money;
+
+    /// Subtracts two amounts with fractional borrow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows, like integer subtraction; use
+    /// `Money::checked_sub` to handle that case.
+    fn sub(self, other: Money) -> Money {
+        self.checked_sub(&other)
+            .expect("attempt to subtract Money with overflow")
+    }
+}
+
+impl std::ops::Neg for Money {
+    type Output = Money;
+
+    /// Negates the amount.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows; use `Money::checked_neg` to handle that
+    /// case.
+    fn neg(self) -> Money {
+        self.checked_neg()
+            .expect("attempt to negate Money with overflow")
+    }
+}
+
+impl std::ops::AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        *self = self.clone() + other;
+    }
+}
+
+impl std::ops::SubAssign for Money {
+    fn sub_assign(&mut self, other: Money) {
+        *self = self.clone() - other;
+    }
+}
+
+impl std::ops::Div<i64> for Money {
+    type Output = Money;
+
+    /// Divides the amount by `divisor`, truncating partial cents toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero or the quotient's whole part does not fit in
+    /// an `i64`, like integer division.
+    fn div(self, divisor: i64) -> Money {
+        Money::from_i128_cents(self.i128_cents() / divisor as i128)
+            .expect("attempt to divide Money with overflow")
+    }
+}
+
+impl Ord for Money {
+    /// Orders amounts by value. Amounts with the same value but differently
+    /// split parts (`Money::new(1, 0)` vs `Money::new(0, 100)`) are ordered by
+    /// their whole part so the ordering stays consistent with `Eq`.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let value = Money::i128_cents;
+        value(self)
+            .cmp(&value(other))
+            .then_with(|| self.whole.cmp(&other.whole))
+    }
+}
+
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl fmt::Display for Money {
-    /// Formats the money value as a US dollar. Properly displays currency symbol
-    /// and negative Money values.
+    /// Formats the money value as a US dollar (see `MoneyFormat::usd`), with
+    /// the sign before the currency symbol.
+    ///
+    /// The sign is taken from the total value in cents, so sub-dollar negative
+    /// amounts such as `Money::new(0, -50)` keep their sign (`-$0.50`).
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Format the whole part with thousands separators
-        let whole_str = format!("{:.*}", 0, self.whole.abs())
-            .chars()
-            .rev()
-            .collect::<String>()
-            .chars()
-            .collect::<Vec<char>>()
-            .chunks(3)
-            .map(|chunk| chunk.iter().collect::<String>())
-            .collect::<Vec<String>>()
-            .join(",")
-            .chars()
-            .rev()
-            .collect::<String>();
-
-        // Format the fractional part with two digits after the decimal point
-        let fractional_str = format!("{:02}", self.fractional.abs());
-
-        // Write the formatted money value to the formatter
-        write!(
-            f,
-            "${}{}.{}",
-            if self.whole < 0 { "-" } else { "" },
-            whole_str,
-            fractional_str
-        )
+        f.write_str(&self.format_with(&MoneyFormat::usd()))
     }
 }
 
@@ -147,21 +813,121 @@ mod tests {
         assert_eq!(money1.whole, 314159265358979);
         assert_eq!(money1.fractional, 32);
 
-        // Test case 2: Decimal with scale > 28
+        // Test case 2: Decimal with scale > 2, truncated to hundredths
         let decimal2 = RustDecimal::new(123456789012345678, 4);
         let money_result1 = Money::from_rust_decimal(decimal2);
         assert!(money_result1.is_ok());
         let money2 = money_result1.unwrap();
         assert_eq!(money2.whole, 12345678901234);
-        assert_eq!(money2.fractional, 5678);
+        assert_eq!(money2.fractional, 56);
 
-        // Test case 3: Decimal with scale = 0
+        // Test case 3: Decimal with scale = 1, scaled to hundredths
         let decimal3 = RustDecimal::new(1234567891, 1);
         let money_result2 = Money::from_rust_decimal(decimal3);
         assert!(money_result2.is_ok());
         let money3 = money_result2.unwrap();
         assert_eq!(money3.whole, 123456789);
-        assert_eq!(money3.fractional, 1);
+        assert_eq!(money3.fractional, 10);
+    }
+
+    #[test]
+    fn test_from_rust_decimal_scales_fractional_to_hundredths() {
+        let money = |mantissa: i64, scale: u32| {
+            Money::from_rust_decimal(RustDecimal::new(mantissa, scale)).unwrap()
+        };
+        assert_eq!(money(15, 1), Money::new(1, 50));
+        assert_eq!(money(15, 1).to_string(), "$1.50");
+        assert_eq!(money(5, 2), Money::new(0, 5));
+        assert_eq!(money(5, 1), Money::new(0, 50));
+        assert_eq!(money(2125, 3), Money::new(2, 12));
+        assert_eq!(money(-2125, 3), Money::new(-2, -12));
+        assert_eq!(money(7, 0), Money::new(7, 0));
+    }
+
+    #[test]
+    fn test_add_carries_fractional() {
+        assert_eq!(Money::new(10, 60) + Money::new(0, 50), Money::new(11, 10));
+        assert_eq!(Money::new(0, 99) + Money::new(0, 1), Money::new(1, 0));
+
+        let mut total = Money::new(0, 0);
+        for _ in 0..3 {
+            total += Money::new(3, 34);
+        }
+        assert_eq!(total, Money::new(10, 2));
+    }
+
+    #[test]
+    fn test_sub_borrows_fractional() {
+        assert_eq!(Money::new(11, 0) - Money::new(0, 1), Money::new(10, 99));
+
+        let mut balance = Money::new(5, 25);
+        balance -= Money::new(2, 50);
+        assert_eq!(balance, Money::new(2, 75));
+    }
+
+    #[test]
+    fn test_arithmetic_crossing_zero() {
+        let difference = Money::new(1, 0) - Money::new(1, 50);
+        assert_eq!(difference, Money::new(0, -50));
+        assert_eq!(difference.to_string(), "-$0.50");
+
+        let difference = Money::new(1, 25) - Money::new(3, 50);
+        assert_eq!(difference, Money::new(-2, -25));
+        assert_eq!(difference.to_string(), "-$2.25");
+
+        assert_eq!(difference + Money::new(2, 25), Money::new(0, 0));
+    }
+
+    #[test]
+    fn test_arithmetic_negative_operands() {
+        assert_eq!(
+            Money::new(-5, -50) + Money::new(0, -75),
+            Money::new(-6, -25)
+        );
+        assert_eq!(Money::new(-5, -50) - Money::new(-10, 0), Money::new(4, 50));
+        assert_eq!(-Money::new(3, 10), Money::new(-3, -10));
+        assert_eq!(-Money::new(-3, -10), Money::new(3, 10));
+        assert_eq!((-Money::new(1234, 5)).to_string(), "-$1,234.05");
+    }
+
+    #[test]
+    fn test_total_cents_overflow() {
+        let unnormalized = Money::new(i64::MAX, i64::MAX);
+        assert_eq!(
+            unnormalized.checked_total_cents(),
+            Err(MoneyError::ValueOverflow)
+        );
+        assert_eq!(unnormalized.saturating_total_cents(), i64::MAX);
+        assert_eq!(Money::new(i64::MIN, -1).saturating_total_cents(), i64::MIN);
+        assert_eq!(Money::new(-10, -99).checked_total_cents(), Ok(-1099));
+        assert_eq!(Money::new(-10, -99).total_cents(), -1099);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn test_total_cents_panics_on_overflow() {
+        Money::new(i64::MAX, 0).total_cents();
+    }
+
+    #[test]
+    fn test_checked_arithmetic_overflow() {
+        let max = Money::new(i64::MAX, 99);
+        assert_eq!(
+            max.checked_add(&Money::new(0, 1)),
+            Err(MoneyError::ValueOverflow)
+        );
+        assert_eq!(
+            Money::new(i64::MIN, -99).checked_sub(&Money::new(0, 1)),
+            Err(MoneyError::ValueOverflow)
+        );
+        assert_eq!(
+            Money::new(i64::MIN, 0).checked_neg(),
+            Err(MoneyError::ValueOverflow)
+        );
+        assert_eq!(
+            max.checked_sub(&Money::new(0, 99)),
+            Ok(Money::new(i64::MAX, 0))
+        );
     }
 
     #[test]
@@ -206,7 +972,7 @@ mod tests {
             whole: -123456789,
             fractional: -50,
         };
-        assert_eq!(money.to_string(), "$-123,456,789.50");
+        assert_eq!(money.to_string(), "-$123,456,789.50");
     }
 
     #[test]
@@ -215,7 +981,8 @@ mod tests {
             whole: -987654321,
             fractional: 3,
         };
-        assert_eq!(money.to_string(), "$-987,654,321.03");
+        // The parts are normalized first: -987654321 + 0.03 is -987654320.97.
+        assert_eq!(money.to_string(), "-$987,654,320.97");
     }
 
     #[test]
@@ -224,6 +991,292 @@ mod tests {
             whole: -123,
             fractional: -5,
         };
-        assert_eq!(money.to_string(), "$-123.05");
+        assert_eq!(money.to_string(), "-$123.05");
+    }
+
+    #[test]
+    fn test_display_negative_sub_dollar() {
+        assert_eq!(Money::new(0, -50).to_string(), "-$0.50");
+        assert_eq!(Money::new(0, -5).to_string(), "-$0.05");
+        assert_eq!(Money::new(0, 50).to_string(), "$0.50");
+        assert_eq!(Money::from_cents(-1).to_string(), "-$0.01");
+    }
+
+    #[test]
+    fn test_money_div() {
+        assert_eq!(Money::new(30, 0) / 30, Money::new(1, 0));
+        assert_eq!(Money::new(10, 0) / 3, Money::new(3, 33));
+        assert_eq!(Money::new(-10, 0) / 3, Money::new(-3, -33));
+        assert_eq!(Money::new(0, 50) / -2, Money::new(0, -25));
+        // Unnormalized parts are divided by their combined value.
+        assert_eq!(Money::new(1, 150) / 1, Money::new(2, 50));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide Money with overflow")]
+    fn test_money_div_panics_on_overflow() {
+        let _ = Money::new(i64::MIN, 0) / -1;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide Money with overflow")]
+    fn test_money_div_panics_on_unnormalized_overflow() {
+        let _ = Money::new(i64::MAX, i64::MAX) / 1;
+    }
+
+    #[test]
+    fn test_money_ordering() {
+        assert!(Money::new(4, 50) < Money::new(9, 99));
+        assert!(Money::new(-1, -50) < Money::new(0, 0));
+        assert!(Money::new(10, 0) > Money::new(9, 99));
+        assert_eq!(
+            Money::new(1, 0).cmp(&Money::new(1, 0)),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_from_rust_decimal_rounded() {
+        let decimal = RustDecimal::new(3145, 3); // 3.145
+        assert_eq!(
+            Money::from_rust_decimal_rounded(decimal, RoundingMode::HalfUp),
+            Ok(Money::new(3, 15))
+        );
+        assert_eq!(
+            Money::from_rust_decimal_rounded(decimal, RoundingMode::Down),
+            Ok(Money::new(3, 14))
+        );
+        assert_eq!(
+            Money::from_rust_decimal_rounded(RustDecimal::new(31401, 4), RoundingMode::Up),
+            Ok(Money::new(3, 15))
+        );
+        assert_eq!(
+            Money::from_rust_decimal_rounded(RustDecimal::new(-3145, 3), RoundingMode::HalfUp),
+            Ok(Money::new(-3, -15))
+        );
+        assert_eq!(
+            Money::from_rust_decimal_rounded(RustDecimal::new(7, 0), RoundingMode::HalfUp),
+            Ok(Money::new(7, 0))
+        );
+    }
+
+    #[test]
+    fn test_to_whole_dollars_rounded() {
+        let money = Money::new(10, 50);
+        assert_eq!(money.to_whole_dollars_rounded(RoundingMode::HalfUp), 11);
+        assert_eq!(money.to_whole_dollars_rounded(RoundingMode::Down), 10);
+        assert_eq!(money.to_whole_dollars_rounded(RoundingMode::Up), 11);
+
+        let money = Money::new(10, 49);
+        assert_eq!(money.to_whole_dollars_rounded(RoundingMode::HalfUp), 10);
+        assert_eq!(money.to_whole_dollars_rounded(RoundingMode::Up), 11);
+
+        let money = Money::new(10, 0);
+        assert_eq!(money.to_whole_dollars_rounded(RoundingMode::Up), 10);
+    }
+
+    #[test]
+    fn test_to_whole_dollars_rounded_negative() {
+        let money = Money::new(-10, -50);
+        assert_eq!(money.to_whole_dollars_rounded(RoundingMode::HalfUp), -11);
+        assert_eq!(money.to_whole_dollars_rounded(RoundingMode::Down), -10);
+        assert_eq!(money.to_whole_dollars_rounded(RoundingMode::Up), -11);
+    }
+
+    #[test]
+    fn test_to_words_zero() {
+        assert_eq!(Money::new(0, 0).to_words(), "zero dollars and zero cents");
+    }
+
+    #[test]
+    fn test_to_words_singular() {
+        assert_eq!(Money::new(1, 1).to_words(), "one dollar and one cent");
+    }
+
+    #[test]
+    fn test_to_words_negative() {
+        assert_eq!(
+            Money::new(-10, -99).to_words(),
+            "negative ten dollars and ninety-nine cents"
+        );
+    }
+
+    #[test]
+    fn test_to_words_large_amount() {
+        assert_eq!(
+            Money::new(1_234_017, 40).to_words(),
+            "one million two hundred thirty-four thousand seventeen dollars and forty cents"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_currency_jpy() {
+        assert_eq!(
+            Money::parse_with_currency("1000.00 JPY"),
+            Ok((Money::new(1000, 0), Currency::Jpy))
+        );
+        assert!(matches!(
+            Money::parse_with_currency("1000.50 JPY"),
+            Err(MoneyError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_with_currency_eur() {
+        assert_eq!(
+            Money::parse_with_currency("9.99 EUR"),
+            Ok((Money::new(9, 99), Currency::Eur))
+        );
+        assert_eq!(
+            Money::parse_with_currency("-1234.5"),
+            Ok((Money::new(-1234, -50), Currency::Usd))
+        );
+    }
+
+    #[test]
+    fn test_parse_with_currency_unknown_code() {
+        assert_eq!(
+            Money::parse_with_currency("9.99 XXX"),
+            Err(MoneyError::UnknownCurrency("XXX".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_from_str_rejects_unsupported_currency() {
+        assert_eq!(
+            "9.99 EUR".parse::<Money>(),
+            Err(MoneyError::UnsupportedCurrency("EUR".to_string()))
+        );
+        assert_eq!(
+            "9.99 XXX".parse::<Money>(),
+            Err(MoneyError::UnknownCurrency("XXX".to_string()))
+        );
+        assert_eq!("9.99 USD".parse::<Money>(), Ok(Money::new(9, 99)));
+    }
+
+    #[test]
+    fn test_from_str_currency_symbol_and_separators() {
+        assert_eq!("10".parse::<Money>(), Ok(Money::new(10, 0)));
+        assert_eq!("10.5".parse::<Money>(), Ok(Money::new(10, 50)));
+        assert_eq!("$10.99".parse::<Money>(), Ok(Money::new(10, 99)));
+        assert_eq!("1,234.50".parse::<Money>(), Ok(Money::new(1234, 50)));
+        assert_eq!("$1,234,567".parse::<Money>(), Ok(Money::new(1_234_567, 0)));
+        assert_eq!("-$0.50".parse::<Money>(), Ok(Money::new(0, -50)));
+        assert_eq!("$-2.25".parse::<Money>(), Ok(Money::new(-2, -25)));
+
+        for malformed in [
+            "10.505", "1O.00", "$", "$$10", "1,23.00", "1234,567", ",123", "12a",
+        ] {
+            assert_eq!(
+                malformed.parse::<Money>(),
+                Err(MoneyError::InvalidFormat(malformed.to_string())),
+                "{}",
+                malformed
+            );
+        }
+    }
+
+    fn plain_format() -> MoneyFormat {
+        MoneyFormat {
+            symbol: String::new(),
+            symbol_position: SymbolPosition::Prefix,
+            grouping_separator: Some(' '),
+            decimal_separator: '.',
+        }
+    }
+
+    #[test]
+    fn test_format_with_eur() {
+        let eur = MoneyFormat::eur();
+        assert_eq!(Money::new(1234, 56).format_with(&eur), "1.234,56 €");
+        assert_eq!(Money::new(0, 5).format_with(&eur), "0,05 €");
+        assert_eq!(Money::new(-1234, -56).format_with(&eur), "-1.234,56 €");
+        assert_eq!(Money::new(0, -50).format_with(&eur), "-0,50 €");
+    }
+
+    #[test]
+    fn test_format_with_no_symbol() {
+        let plain = plain_format();
+        assert_eq!(Money::new(1_234_567, 8).format_with(&plain), "1 234 567.08");
+        assert_eq!(Money::new(999, 0).format_with(&plain), "999.00");
+        assert_eq!(
+            Money::new(-1_234_567, -8).format_with(&plain),
+            "-1 234 567.08"
+        );
+    }
+
+    #[test]
+    fn test_display_mixed_sign_parts() {
+        assert_eq!(Money::new(-1, 50).to_string(), "-$0.50");
+        assert_eq!(Money::new(1, -50).to_string(), "$0.50");
+        assert_eq!(Money::new(0, 150).to_string(), "$1.50");
+        assert_eq!(Money::new(-987654321, 3).to_string(), "-$987,654,320.97");
+        assert_eq!(
+            Money::new(-1, 50).format_with(&MoneyFormat::eur()),
+            "-0,50 €"
+        );
+    }
+
+    #[test]
+    fn test_format_with_usd_matches_display() {
+        for money in [
+            Money::new(1234, 5),
+            Money::new(-1234, -5),
+            Money::new(i64::MIN, -99),
+        ] {
+            assert_eq!(money.format_with(&MoneyFormat::usd()), money.to_string());
+        }
+        assert_eq!(Money::new(-1234, -5).to_string(), "-$1,234.05");
+        assert_eq!(
+            Money::new(i64::MIN, -99).to_string(),
+            "-$9,223,372,036,854,775,808.99"
+        );
+    }
+
+    #[test]
+    fn test_from_str_parenthesized_negative() {
+        assert_eq!("($123.45)".parse::<Money>(), Ok(Money::new(-123, -45)));
+        assert_eq!("(1,000)".parse::<Money>(), Ok(Money::new(-1000, 0)));
+        assert_eq!("(0.50) USD".parse::<Money>(), Ok(Money::new(0, -50)));
+        assert_eq!("-$123.45".parse::<Money>(), Ok(Money::new(-123, -45)));
+
+        for malformed in [
+            "(-$1.00)",
+            "($-1.00)",
+            "($1.00",
+            "$1.00)",
+            "()",
+            "(($1.00))",
+        ] {
+            assert_eq!(
+                malformed.parse::<Money>(),
+                Err(MoneyError::InvalidFormat(malformed.to_string())),
+                "{}",
+                malformed
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_display_round_trip() {
+        for money in [
+            Money::new(0, 0),
+            Money::new(0, 5),
+            Money::new(0, -50),
+            Money::new(1234, 50),
+            Money::new(-987_654_321, -3),
+            Money::new(i64::MAX, 99),
+        ] {
+            assert_eq!(format!("{}", money).parse::<Money>(), Ok(money));
+        }
+    }
+
+    #[test]
+    fn test_from_str_only_accepts_usd() {
+        assert_eq!("9.99".parse::<Money>(), Ok(Money::new(9, 99)));
+        assert_eq!("9.99 USD".parse::<Money>(), Ok(Money::new(9, 99)));
+        assert!("9.99 EUR".parse::<Money>().is_err());
+        assert!("9.999".parse::<Money>().is_err());
+        assert!("ten".parse::<Money>().is_err());
     }
 }
@@ -1,22 +1,24 @@
 use std::str::FromStr;
 
-use chrono::{NaiveDateTime, NaiveTime, Timelike};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use structsy::derive::PersistentEmbedded;
 
 use crate::error::AppError;
+use crate::models::notify::SendFrequency;
 
 static RE_EPOCH: Lazy<Regex> = Lazy::new(|| init_regex_epoch());
 
 fn init_regex_epoch() -> Regex {
-    Regex::new(r"(([1-9]{1}[0-9]*)([dwmy]))(([1-9]{1}[0-9]*)x)?")
+    Regex::new(r"(([1-9]{1}[0-9]*)(me|[dwmy]))(([0-9]+)x)?")
         .expect("failed to initialize epoch regex")
 }
 
 /// Represents the calendar data set for an epoch duration, associated with
 /// amount: i64 and coefficient: i64.
 #[derive(Debug, Copy, Clone, PartialEq, PersistentEmbedded)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CalendarData {
     pub amount: i64,
     pub coefficient: i64,
@@ -31,6 +33,92 @@ impl CalendarData {
     }
 }
 
+/// A set of weekdays, used to recur on several days of the week.
+///
+/// # Examples
+///
+/// ```
+/// use event_pulse::models::WeeklyMask;
+/// use chrono::Weekday;
+///
+/// let mask = WeeklyMask::from_weekdays(&[Weekday::Mon, Weekday::Wed, Weekday::Fri]);
+/// assert!(mask.contains(Weekday::Wed));
+/// assert_eq!(mask.to_string(), "MO,WE,FR");
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PersistentEmbedded)]
+pub struct WeeklyMask {
+    /// One bit per weekday, bit 0 being Monday.
+    bits: u8,
+}
+
+/// iCalendar weekday codes, Monday first.
+const WEEKDAY_CODES: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+impl WeeklyMask {
+    /// Creates a mask containing the given weekdays.
+    pub fn from_weekdays(weekdays: &[Weekday]) -> Self {
+        weekdays
+            .iter()
+            .fold(Self::default(), |mask, weekday| mask.with(*weekday))
+    }
+
+    /// Returns a copy of the mask with `weekday` added.
+    pub fn with(self, weekday: Weekday) -> Self {
+        Self {
+            bits: self.bits | 1 << weekday.num_days_from_monday(),
+        }
+    }
+
+    /// Returns true if the mask contains `weekday`.
+    pub fn contains(&self, weekday: Weekday) -> bool {
+        self.bits & 1 << weekday.num_days_from_monday() != 0
+    }
+
+    /// Returns the weekdays in the mask, Monday first.
+    pub fn weekdays(&self) -> Vec<Weekday> {
+        (0..7)
+            .filter_map(|offset| Weekday::try_from(offset).ok())
+            .filter(|weekday| self.contains(*weekday))
+            .collect()
+    }
+
+    /// Returns true if the mask contains no weekday.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+}
+
+impl FromStr for WeeklyMask {
+    type Err = AppError;
+
+    /// Parses a comma-separated list of iCalendar weekday codes, e.g. `"MO,WE,FR"`.
+    fn from_str(s: &str) -> Result<WeeklyMask, AppError> {
+        s.split(',')
+            .map(|code| {
+                WEEKDAY_CODES
+                    .iter()
+                    .position(|known| *known == code.trim())
+                    .and_then(|offset| Weekday::try_from(offset as u8).ok())
+                    .ok_or_else(|| {
+                        AppError::InvalidInputString(format!("Invalid weekday code: {}", code))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|weekdays| WeeklyMask::from_weekdays(&weekdays))
+    }
+}
+
+impl std::fmt::Display for WeeklyMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let codes: Vec<_> = self
+            .weekdays()
+            .iter()
+            .map(|weekday| WEEKDAY_CODES[weekday.num_days_from_monday() as usize])
+            .collect();
+        write!(f, "{}", codes.join(","))
+    }
+}
+
 /// A time range represented by various units with duration and coefficient.
 ///
 /// An `Epoch` can represent time duration in units such as year(s), month(s),
@@ -43,12 +131,22 @@ pub enum Epoch {
     Year(CalendarData),
     /// Represents a duration in months with an associated tuple, CalendarData { amount, coefficient }
     Month(CalendarData),
+    /// Represents a duration in months landing on the last day of each month, with an
+    /// associated tuple, CalendarData { amount, coefficient }
+    MonthEnd(CalendarData),
     /// Represents a duration in weeks with an associated tuple, CalendarData { amount, coefficient }
     Week(CalendarData),
     /// Represents a duration in days with an associated tuple, CalendarData { amount, coefficient }
     Day(CalendarData),
+    /// Represents a duration in weeks recurring on every weekday of the mask, with an
+    /// associated tuple, CalendarData { amount, coefficient }
+    WeeklyOn(WeeklyMask, CalendarData),
 }
 
+/// How many days a sample gap may be off a whole number of calendar months or
+/// years for `Epoch::infer` to still treat it as monthly or yearly.
+pub const INFER_TOLERANCE_DAYS: i64 = 3;
+
 impl Epoch {
     /// Creates a new `Epoch` with the specified unit, and tuple:
     /// CalendarData {amount: i64, coefficient: i64}
@@ -56,7 +154,7 @@ impl Epoch {
     /// # Arguments
     ///
     /// * `unit` - A string slice representing the unit of time ('y' for year, 'm' for month,
-    ///            'w' for week, 'd' for day).
+    ///            'me' for month end, 'w' for week, 'd' for day).
     /// * CalendarData - tuple with `amount`, `coefficient`
     /// * `amount` - The amount of time for the given unit.
     /// * `coefficient` - The coefficient or frequency associated with the duration.
@@ -78,12 +176,66 @@ impl Epoch {
         match unit {
             "y" => Epoch::Year(calendar_data),
             "m" => Epoch::Month(calendar_data),
+            "me" => Epoch::MonthEnd(calendar_data),
             "w" => Epoch::Week(calendar_data),
             "d" => Epoch::Day(calendar_data),
             _ => Epoch::SingleDay,
         }
     }
 
+    /// Infers the epoch of a recurrence from two consecutive sample dates, e.g.
+    /// observed charge dates, with a coefficient of 1.
+    ///
+    /// Only the dates matter, not the time of day, nor which sample comes
+    /// first. A gap of exactly N calendar months or years maps to `Month(N)` or
+    /// `Year(N)`, a whole number of weeks to `Week(N)`, a gap within
+    /// `INFER_TOLERANCE_DAYS` of N months or years (charges that slipped over a
+    /// weekend) to `Month(N)` or `Year(N)`, and anything else to `Day(N)`. Two
+    /// samples on the same date give `SingleDay`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{CalendarData, Epoch};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let first = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+    /// let second = Utc.with_ymd_and_hms(2024, 2, 15, 9, 0, 0).unwrap();
+    /// assert_eq!(Epoch::infer(first, second), Epoch::Month(CalendarData::new(1, 1)));
+    /// ```
+    pub fn infer(first: DateTime<Utc>, second: DateTime<Utc>) -> Epoch {
+        let (from, to) = if first <= second {
+            (first.date_naive(), second.date_naive())
+        } else {
+            (second.date_naive(), first.date_naive())
+        };
+        let days = to.signed_duration_since(from).num_days();
+        if days == 0 {
+            return Epoch::SingleDay;
+        }
+
+        // Distance in days between `to` and `from` plus the nearest whole
+        // number of `months_per_unit` months.
+        let calendar_fit = |months_per_unit: i64, approx_days: f64| {
+            let units = (days as f64 / approx_days).round() as i64;
+            let end = add_months(from.and_time(NaiveTime::MIN), units * months_per_unit)?;
+            let off = end.date().signed_duration_since(to).num_days().abs();
+            (units > 0).then_some((units, off))
+        };
+        let years = calendar_fit(12, 365.25);
+        let months = calendar_fit(1, 30.44);
+        let with = |amount: i64| CalendarData::new(amount, 1);
+
+        match (years, months) {
+            (Some((units, 0)), _) => Epoch::Year(with(units)),
+            (_, Some((units, 0))) => Epoch::Month(with(units)),
+            _ if days % crate::DAYS_IN_WEEK == 0 => Epoch::Week(with(days / crate::DAYS_IN_WEEK)),
+            (Some((units, off)), _) if off <= INFER_TOLERANCE_DAYS => Epoch::Year(with(units)),
+            (_, Some((units, off))) if off <= INFER_TOLERANCE_DAYS => Epoch::Month(with(units)),
+            _ => Epoch::Day(with(days)),
+        }
+    }
+
     /// Returns the frequency coefficient associated with the `Epoch`.
     ///
     /// For `SingleDay`, returns 1 as it represents a single day. For other variants
@@ -107,8 +259,10 @@ impl Epoch {
             Self::SingleDay => 1,
             Self::Year(calendar_data)
             | Self::Month(calendar_data)
+            | Self::MonthEnd(calendar_data)
             | Self::Week(calendar_data)
-            | Self::Day(calendar_data) => calendar_data.coefficient,
+            | Self::Day(calendar_data)
+            | Self::WeeklyOn(_, calendar_data) => calendar_data.coefficient,
         }
     }
 
@@ -116,7 +270,9 @@ impl Epoch {
     ///
     /// This method calculates the number of days based on the epoch type and the
     /// provided starting date (`since`). It considers months and leap years to
-    /// compute the exact number of days.
+    /// compute the exact number of days. When the target month is shorter than
+    /// the day of `since`, the end date is clamped to the last day of that month
+    /// (Jan 31 + 1 month lands on Feb 28/29).
     ///
     /// # Arguments
     ///
@@ -138,47 +294,26 @@ impl Epoch {
     /// assert_eq!(days_elapsed, 29); // tests leap month duration
     /// ```
     pub fn calculate_days_since(&self, since: NaiveDateTime) -> i64 {
-        use chrono::{Datelike, NaiveDate};
-
-        let since_date = since.date();
         match self {
             Self::Month(cd) => {
-                let mut current_year = since_date.year();
-                let mut current_month = since_date.month() as i32;
-                for _ in 0..cd.coefficient {
-                    current_month += cd.amount as i32;
-                    if current_month > 12 {
-                        current_year += 1;
-                        current_month -= 12;
-                    }
-                }
-                let end_datetime = NaiveDateTime::new(
-                    NaiveDate::from_ymd_opt(current_year, current_month as u32, since_date.day())
-                        .expect("failed to create NaiveDate from NaiveDateTime"),
-                    NaiveTime::from_hms_opt(
-                        since.time().hour(),
-                        since.time().minute(),
-                        since.time().second(),
-                    )
-                    .expect("failed to create NaiveTime"),
-                );
+                let end_datetime = add_months(since, cd.amount * cd.coefficient)
+                    .expect("epoch end date out of range");
+                end_datetime.signed_duration_since(since).num_days()
+            }
+            Self::MonthEnd(cd) => {
+                let end_datetime = add_months(since, cd.amount * cd.coefficient)
+                    .and_then(month_end)
+                    .expect("epoch end date out of range");
                 end_datetime.signed_duration_since(since).num_days()
             }
             Self::Year(cd) => {
-                let end_year = since_date.year() + (cd.coefficient * cd.amount) as i32;
-                let end_datetime = NaiveDateTime::new(
-                    NaiveDate::from_ymd_opt(end_year, since_date.month(), since_date.day())
-                        .expect("failed to create NaiveDate from NaiveDateTime"),
-                    NaiveTime::from_hms_opt(
-                        since.time().hour(),
-                        since.time().minute(),
-                        since.time().second(),
-                    )
-                    .expect("failed to create NaiveTime"),
-                );
+                let end_datetime = add_months(since, 12 * cd.amount * cd.coefficient)
+                    .expect("epoch end date out of range");
                 end_datetime.signed_duration_since(since).num_days()
             }
-            Self::Week(cd) => cd.amount * cd.coefficient * crate::DAYS_IN_WEEK as i64,
+            Self::Week(cd) | Self::WeeklyOn(_, cd) => {
+                cd.amount * cd.coefficient * crate::DAYS_IN_WEEK as i64
+            }
             Self::Day(cd) => cd.amount * cd.coefficient,
             Self::SingleDay => 1,
         }
@@ -187,6 +322,11 @@ impl Epoch {
     /// Converts the Epoch variant into a chrono Duration representing the
     /// duration in days.
     ///
+    /// The duration is approximate for calendar-based epochs: months are
+    /// `DAYS_IN_MONTH` (30) days and years `DAYS_IN_YEAR` (365) days, so e.g. a
+    /// month starting Jan 1 or a year spanning Feb 29 is off by a day. Use
+    /// [`Epoch::to_duration_from`] when the start date is known.
+    ///
     /// # Returns
     ///
     /// A chrono Duration representing the duration in days.
@@ -203,40 +343,859 @@ impl Epoch {
     /// // Verify that the duration corresponds to the expected number of days in a year
     /// assert_eq!(year_duration, Duration::try_days(365).expect("invalid number of days"));
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amount * coefficient` days of the epoch's unit overflow a
+    /// `chrono::Duration`. Use [`Epoch::to_duration_checked`] for untrusted input.
     pub fn to_duration(&self) -> chrono::Duration {
+        self.to_duration_checked().expect("Invalid number of days")
+    }
+
+    /// Returns the exact duration of the period starting at `start`, using
+    /// calendar arithmetic for months and years like `calculate_days_since`.
+    ///
+    /// Day and week based epochs have a fixed length and match `to_duration`.
+    /// If the end of the period is out of the representable date range, the
+    /// approximate `to_duration` is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{CalendarData, Epoch};
+    /// use chrono::{Duration, NaiveDate};
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let monthly = Epoch::Month(CalendarData::new(1, 1));
+    /// assert_eq!(monthly.to_duration_from(start), Duration::try_days(31).unwrap());
+    /// assert_eq!(monthly.to_duration(), Duration::try_days(30).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fallback `to_duration` does.
+    pub fn to_duration_from(&self, start: NaiveDateTime) -> chrono::Duration {
+        let months = |cd: &CalendarData, per_unit: i64| {
+            cd.amount.checked_mul(cd.coefficient)?.checked_mul(per_unit)
+        };
+        let end = match self {
+            Self::Month(cd) => months(cd, 1).and_then(|months| add_months(start, months)),
+            Self::MonthEnd(cd) => months(cd, 1)
+                .and_then(|months| add_months(start, months))
+                .and_then(month_end),
+            Self::Year(cd) => months(cd, 12).and_then(|months| add_months(start, months)),
+            Self::Week(_) | Self::WeeklyOn(..) | Self::Day(_) | Self::SingleDay => None,
+        };
+        end.map_or_else(
+            || self.to_duration(),
+            |end| end.signed_duration_since(start),
+        )
+    }
+
+    /// Returns the approximate number of days in one period of the epoch,
+    /// `amount * coefficient` times the days in its unit.
+    ///
+    /// This is the date-free counterpart to `calculate_days_since`: months are
+    /// `DAYS_IN_MONTH` (30) days and years `DAYS_IN_YEAR` (365) days regardless of
+    /// the calendar. `SingleDay` returns 1. The result saturates at `i64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{Epoch, CalendarData};
+    ///
+    /// assert_eq!(Epoch::Month(CalendarData::new(1, 1)).approx_days_per_period(), 30);
+    /// assert_eq!(Epoch::Week(CalendarData::new(2, 1)).approx_days_per_period(), 14);
+    /// ```
+    pub fn approx_days_per_period(&self) -> i64 {
+        let (calendar_data, days_per_unit) = match self {
+            Self::SingleDay => return 1,
+            Self::Year(calendar_data) => (calendar_data, crate::DAYS_IN_YEAR),
+            Self::Month(calendar_data) | Self::MonthEnd(calendar_data) => {
+                (calendar_data, crate::DAYS_IN_MONTH)
+            }
+            Self::Week(calendar_data) | Self::WeeklyOn(_, calendar_data) => {
+                (calendar_data, crate::DAYS_IN_WEEK)
+            }
+            Self::Day(calendar_data) => (calendar_data, 1),
+        };
+        calendar_data
+            .amount
+            .saturating_mul(calendar_data.coefficient)
+            .saturating_mul(days_per_unit)
+    }
+
+    /// Returns the fixed-interval `SendFrequency` whose interval is closest to
+    /// the epoch's period, e.g. `BiWeekly` for a 2-week epoch or `Quarterly`
+    /// for a 3-month one.
+    ///
+    /// Periods are compared in days using `approx_days_per_period`; ties go to
+    /// the more frequent option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{notify::SendFrequency, CalendarData, Epoch};
+    ///
+    /// let epoch = Epoch::Week(CalendarData::new(2, 1));
+    /// assert_eq!(epoch.closest_send_frequency(), SendFrequency::BiWeekly);
+    /// ```
+    pub fn closest_send_frequency(&self) -> SendFrequency {
+        let days = self.approx_days_per_period();
+        [
+            (SendFrequency::Daily, 1),
+            (SendFrequency::Weekly, crate::DAYS_IN_WEEK),
+            (SendFrequency::BiWeekly, 2 * crate::DAYS_IN_WEEK),
+            (SendFrequency::Monthly, crate::DAYS_IN_MONTH),
+            (SendFrequency::Quarterly, 3 * crate::DAYS_IN_MONTH),
+        ]
+        .into_iter()
+        .min_by_key(|(_, interval)| days.abs_diff(*interval))
+        .map(|(frequency, _)| frequency)
+        .expect("candidate frequencies are not empty")
+    }
+
+    /// Converts the Epoch variant into a chrono Duration representing the
+    /// duration in days, returning an error instead of panicking when the
+    /// duration overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{Epoch, CalendarData};
+    ///
+    /// assert!(Epoch::Week(CalendarData::new(2, 1)).to_duration_checked().is_ok());
+    /// assert!(Epoch::Year(CalendarData::new(i64::MAX / 2, 2)).to_duration_checked().is_err());
+    /// ```
+    pub fn to_duration_checked(&self) -> Result<chrono::Duration, AppError> {
         use chrono::Duration;
 
+        let (calendar_data, days_per_unit) = match self {
+            Self::SingleDay => return Ok(Duration::try_days(1).expect("1 day")),
+            Self::Year(calendar_data) => (calendar_data, crate::DAYS_IN_YEAR),
+            Self::Month(calendar_data) | Self::MonthEnd(calendar_data) => {
+                (calendar_data, crate::DAYS_IN_MONTH)
+            }
+            Self::Week(calendar_data) | Self::WeeklyOn(_, calendar_data) => {
+                (calendar_data, crate::DAYS_IN_WEEK)
+            }
+            Self::Day(calendar_data) => (calendar_data, 1),
+        };
+        calendar_data
+            .amount
+            .checked_mul(calendar_data.coefficient)
+            .and_then(|units| units.checked_mul(days_per_unit))
+            .and_then(Duration::try_days)
+            .ok_or_else(|| AppError::Overflow(format!("Epoch {} overflows a duration", self)))
+    }
+
+    /// Returns the `n`th occurrence of the epoch counted from `start`, where the
+    /// 0th occurrence is `start` itself.
+    ///
+    /// Each period spans `amount * coefficient` units. Months and years use
+    /// calendar arithmetic, clamping the day to the last valid day of the target
+    /// month (Jan 31 + 1 month lands on Feb 28/29). `MonthEnd` occurrences,
+    /// including the 0th, always land on the last day of their month. `SingleDay`
+    /// only has the 0th occurrence. Returns `None` when the occurrence does not
+    /// exist or cannot be represented.
+    pub fn nth_occurrence(&self, start: DateTime<Utc>, n: u32) -> Option<DateTime<Utc>> {
+        use chrono::{Datelike, Duration};
+
+        let n = n as i64;
+        match self {
+            Self::SingleDay => (n == 0).then_some(start),
+            Self::Year(cd) => {
+                let months = cd.amount.checked_mul(cd.coefficient)?.checked_mul(12 * n)?;
+                add_months(start.naive_utc(), months).map(|dt| dt.and_utc())
+            }
+            Self::Month(cd) => {
+                let months = cd.amount.checked_mul(cd.coefficient)?.checked_mul(n)?;
+                add_months(start.naive_utc(), months).map(|dt| dt.and_utc())
+            }
+            Self::MonthEnd(cd) => {
+                let months = cd.amount.checked_mul(cd.coefficient)?.checked_mul(n)?;
+                add_months(start.naive_utc(), months)
+                    .and_then(month_end)
+                    .map(|dt| dt.and_utc())
+            }
+            Self::Week(cd) => {
+                let days = cd
+                    .amount
+                    .checked_mul(cd.coefficient)?
+                    .checked_mul(crate::DAYS_IN_WEEK * n)?;
+                start.checked_add_signed(Duration::try_days(days)?)
+            }
+            Self::Day(cd) => {
+                let days = cd.amount.checked_mul(cd.coefficient)?.checked_mul(n)?;
+                start.checked_add_signed(Duration::try_days(days)?)
+            }
+            Self::WeeklyOn(mask, cd) => {
+                // Index the occurrences from the Monday of the start week, skipping
+                // the mask's weekdays that fall before `start` in that first week.
+                let weekdays = mask.weekdays();
+                let per_period = weekdays.len() as i64;
+                if per_period == 0 {
+                    return None;
+                }
+                let start_offset = start.weekday().num_days_from_monday();
+                let skipped = weekdays
+                    .iter()
+                    .filter(|weekday| weekday.num_days_from_monday() < start_offset)
+                    .count() as i64;
+                let index = n.checked_add(skipped)?;
+                let period_days = cd
+                    .amount
+                    .checked_mul(cd.coefficient)?
+                    .checked_mul(crate::DAYS_IN_WEEK)?;
+                let days = (index / per_period).checked_mul(period_days)?
+                    + weekdays[(index % per_period) as usize].num_days_from_monday() as i64
+                    - start_offset as i64;
+                start.checked_add_signed(Duration::try_days(days)?)
+            }
+        }
+    }
+
+    /// Returns a lazy, unbounded iterator over the occurrences of the epoch
+    /// counted from `start`: `start` itself, then one period later, and so on.
+    ///
+    /// Occurrences follow `nth_occurrence`, so monthly recurrences keep the day
+    /// of month of `start`, clamped at month end, as in `calculate_days_since`.
+    /// `SingleDay` yields only `start`. Iteration stops if an occurrence cannot
+    /// be represented; bound it with `take` or `take_while`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{CalendarData, Epoch};
+    /// use chrono::NaiveDate;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2023, 1, 31)
+    ///     .unwrap()
+    ///     .and_hms_opt(9, 0, 0)
+    ///     .unwrap();
+    /// let monthly = Epoch::Month(CalendarData::new(1, 1));
+    /// let days: Vec<_> = monthly
+    ///     .occurrences_from(start)
+    ///     .take(3)
+    ///     .map(|dt| dt.date())
+    ///     .collect();
+    /// assert_eq!(days, vec![
+    ///     NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2023, 2, 28).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2023, 3, 31).unwrap(),
+    /// ]);
+    /// ```
+    pub fn occurrences_from(&self, start: NaiveDateTime) -> impl Iterator<Item = NaiveDateTime> {
+        let epoch = *self;
+        let start = start.and_utc();
+        (0..).map_while(move |n| epoch.nth_occurrence(start, n).map(|dt| dt.naive_utc()))
+    }
+
+    /// Returns a lazy iterator over the occurrences of the epoch in `[start, until)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{CalendarData, Epoch};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let until = Utc.with_ymd_and_hms(2024, 1, 22, 9, 0, 0).unwrap();
+    /// let weekly = Epoch::Week(CalendarData::new(1, 1));
+    /// assert_eq!(weekly.occurrences(start, until).count(), 3);
+    /// ```
+    pub fn occurrences(
+        &self,
+        start: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> impl Iterator<Item = DateTime<Utc>> {
+        self.occurrences_from(start.naive_utc())
+            .map(|occurrence| occurrence.and_utc())
+            .take_while(move |occurrence| *occurrence < until)
+    }
+
+    /// Collects the occurrences of the epoch in `[start, until)`, like
+    /// `occurrences`, into a vector.
+    ///
+    /// `Day`, `Week` and `SingleDay` epochs have a fixed period, so the number of
+    /// occurrences is computed upfront, the vector is allocated once and each
+    /// occurrence is `start` plus a multiple of the period. Calendar based
+    /// epochs fall back to walking `occurrences`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{CalendarData, Epoch};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let until = Utc.with_ymd_and_hms(2024, 1, 22, 9, 0, 0).unwrap();
+    /// let weekly = Epoch::Week(CalendarData::new(1, 1));
+    /// assert_eq!(weekly.collect_occurrences(start, until).len(), 3);
+    /// ```
+    pub fn collect_occurrences(
+        &self,
+        start: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Vec<DateTime<Utc>> {
+        let period = match self {
+            Self::SingleDay => {
+                return if start < until {
+                    vec![start]
+                } else {
+                    Vec::new()
+                };
+            }
+            Self::Day(_) | Self::Week(_) => self.to_duration_checked().ok(),
+            _ => None,
+        };
+        let period = match period {
+            Some(period) if period > chrono::Duration::zero() => period,
+            _ => return self.occurrences(start, until).collect(),
+        };
+        if until <= start {
+            return Vec::new();
+        }
+
+        // Index of the last period boundary at or before `until`, which is
+        // itself excluded when it lands exactly on `until`.
+        let period_secs = period.num_seconds();
+        let mut count = until.signed_duration_since(start).num_seconds() / period_secs + 1;
+        let last = chrono::Duration::try_seconds(period_secs.saturating_mul(count - 1))
+            .and_then(|offset| start.checked_add_signed(offset));
+        if last.is_none_or(|last| last >= until) {
+            count -= 1;
+        }
+
+        let mut occurrences = Vec::with_capacity(count as usize);
+        for n in 0..count {
+            match chrono::Duration::try_seconds(period_secs * n)
+                .and_then(|offset| start.checked_add_signed(offset))
+            {
+                Some(occurrence) => occurrences.push(occurrence),
+                None => break,
+            }
+        }
+        occurrences
+    }
+
+    /// Returns the dates of the first `count` occurrences counted from `start`,
+    /// `start` included.
+    ///
+    /// Fewer dates are returned if the epoch runs out of occurrences, e.g. a
+    /// `SingleDay` only has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{CalendarData, Epoch};
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let dates = Epoch::Week(CalendarData::new(1, 1)).next_dates(start, 2);
+    /// assert_eq!(dates, vec![
+    ///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 8).unwrap(),
+    /// ]);
+    /// ```
+    pub fn next_dates(&self, start: DateTime<Utc>, count: usize) -> Vec<NaiveDate> {
+        (0..)
+            .map_while(|n| self.nth_occurrence(start, n))
+            .take(count)
+            .map(|occurrence| occurrence.date_naive())
+            .collect()
+    }
+
+    /// Returns a lazy iterator over the ISO week starts (Mondays at 00:00 UTC) in
+    /// `[start, until)`, every `amount * coefficient` weeks from the first Monday
+    /// at or after `start`.
+    ///
+    /// Unlike [`Epoch::occurrences`], which recurs on the weekday of `start`, the
+    /// occurrences are anchored to ISO weeks. Only weekly epochs have ISO week
+    /// occurrences; other variants yield nothing.
+    pub fn iso_week_occurrences(
+        &self,
+        start: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> impl Iterator<Item = DateTime<Utc>> {
+        use chrono::{Datelike, Duration};
+
+        let period_weeks = match self {
+            Self::Week(cd) | Self::WeeklyOn(_, cd) => cd.amount.checked_mul(cd.coefficient),
+            _ => None,
+        };
+        let date = start.date_naive();
+        let mut first_monday = (date
+            - Duration::try_days(date.weekday().num_days_from_monday() as i64).expect("days"))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+        if first_monday < start {
+            first_monday += Duration::try_weeks(1).expect("1 week");
+        }
+
+        period_weeks
+            .into_iter()
+            .flat_map(move |weeks| {
+                (0..).map_while(move |n: i64| {
+                    let days = weeks.checked_mul(crate::DAYS_IN_WEEK)?.checked_mul(n)?;
+                    first_monday.checked_add_signed(Duration::try_days(days)?)
+                })
+            })
+            .take_while(move |occurrence| *occurrence < until)
+    }
+
+    /// Returns the first occurrence counted from `start` that is strictly after
+    /// `after`, or `None` if the epoch has no further occurrences.
+    pub fn next_occurrence(
+        &self,
+        start: DateTime<Utc>,
+        after: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        self.next_occurrence_index(start, after)
+            .and_then(|n| self.nth_occurrence(start, n))
+    }
+
+    /// Returns the `[from, to)` bounds of the period counted from `start` that
+    /// contains `at`, or `None` if `at` is before `start` or past the last
+    /// occurrence. The single period of `SingleDay` lasts one day.
+    pub fn period_containing(
+        &self,
+        start: DateTime<Utc>,
+        at: DateTime<Utc>,
+    ) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        if at < start {
+            return None;
+        }
+        if let Self::SingleDay = self {
+            let to = start.checked_add_signed(self.to_duration_checked().ok()?)?;
+            return (at < to).then_some((start, to));
+        }
+        let n = self.next_occurrence_index(start, at)?;
+        Some((
+            self.nth_occurrence(start, n.checked_sub(1)?)?,
+            self.nth_occurrence(start, n)?,
+        ))
+    }
+
+    /// Returns whichever occurrence counted from `start`, the one at or before
+    /// `target` or the one after it, is closer to `target`. Ties snap back to
+    /// the earlier occurrence.
+    ///
+    /// A `target` before the first occurrence snaps to the first occurrence,
+    /// and one past the last occurrence (e.g. of a `SingleDay`) to the last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{CalendarData, Epoch};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let weekly = Epoch::Week(CalendarData::new(1, 1));
+    /// let target = Utc.with_ymd_and_hms(2024, 1, 13, 0, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     weekly.snap_to_occurrence(target, start),
+    ///     Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap()
+    /// );
+    /// ```
+    pub fn snap_to_occurrence(&self, target: DateTime<Utc>, start: DateTime<Utc>) -> DateTime<Utc> {
+        let n = self.next_occurrence_index(start, target);
+        let previous = n
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|n| self.nth_occurrence(start, n));
+        let next = n.and_then(|n| self.nth_occurrence(start, n));
+        match (previous, next) {
+            (Some(previous), Some(next)) => {
+                if next.signed_duration_since(target) < target.signed_duration_since(previous) {
+                    next
+                } else {
+                    previous
+                }
+            }
+            (Some(occurrence), None) | (None, Some(occurrence)) => occurrence,
+            (None, None) => self.last_occurrence(start).unwrap_or(start),
+        }
+    }
+
+    /// Returns the last occurrence counted from `start` of an epoch that runs
+    /// out of occurrences, i.e. the 0th occurrence of a `SingleDay`.
+    fn last_occurrence(&self, start: DateTime<Utc>) -> Option<DateTime<Utc>> {
         match self {
-            Self::SingleDay => Duration::try_days(1).expect("1 day"),
-            Self::Year(calendar_data) => Duration::try_days(
-                (calendar_data.amount * calendar_data.coefficient * crate::DAYS_IN_YEAR) as i64,
-            )
-            .expect("Invalid number of days"),
-            Self::Month(calendar_data) => Duration::try_days(
-                (calendar_data.amount * calendar_data.coefficient * crate::DAYS_IN_MONTH) as i64,
-            )
-            .expect("Invalid number of days"),
-            Self::Week(calendar_data) => Duration::try_days(
-                (calendar_data.amount * calendar_data.coefficient * crate::DAYS_IN_WEEK) as i64,
-            )
-            .expect("Invalid number of days"),
-            Self::Day(calendar_data) => {
-                Duration::try_days((calendar_data.amount * calendar_data.coefficient) as i64)
-                    .expect("Invalid number of days")
+            Self::SingleDay => self.nth_occurrence(start, 0),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the first occurrence strictly after `after`, or
+    /// `None` if there is none, e.g. for an epoch with a zero-length period or
+    /// one too long to fit in a duration.
+    fn next_occurrence_index(&self, start: DateTime<Utc>, after: DateTime<Utc>) -> Option<u32> {
+        if after < start {
+            return Some(0);
+        }
+        let period_secs = self.to_duration_checked().ok()?.num_seconds();
+        if period_secs <= 0 {
+            return None;
+        }
+        // Jump close to `after` using the approximate period length, which can
+        // overshoot over long spans (months are taken as 30 days), so step back
+        // until the previous occurrence is at or before `after`, then walk
+        // forward.
+        let elapsed_secs = after.signed_duration_since(start).num_seconds();
+        let mut n = u32::try_from(elapsed_secs / period_secs).ok()?;
+        while n > 0
+            && self
+                .nth_occurrence(start, n - 1)
+                .is_none_or(|occurrence| occurrence > after)
+        {
+            n -= 1;
+        }
+        loop {
+            if self.nth_occurrence(start, n)? > after {
+                return Some(n);
             }
+            n = n.checked_add(1)?;
         }
     }
+
+    /// Converts the epoch into an iCalendar recurrence rule (RFC 5545).
+    ///
+    /// The rule's `INTERVAL` is the full period, `amount * coefficient`, so
+    /// `3m4x` is rendered as `FREQ=MONTHLY;INTERVAL=12`. `SingleDay` is rendered
+    /// as a one-off `FREQ=DAILY;COUNT=1`.
+    pub fn to_rrule(&self) -> String {
+        match self {
+            Self::SingleDay => "FREQ=DAILY;COUNT=1".to_string(),
+            Self::Year(cd) => format!("FREQ=YEARLY;INTERVAL={}", cd.amount * cd.coefficient),
+            Self::Month(cd) => format!("FREQ=MONTHLY;INTERVAL={}", cd.amount * cd.coefficient),
+            Self::MonthEnd(cd) => format!(
+                "FREQ=MONTHLY;INTERVAL={};BYMONTHDAY=-1",
+                cd.amount * cd.coefficient
+            ),
+            Self::Week(cd) => format!("FREQ=WEEKLY;INTERVAL={}", cd.amount * cd.coefficient),
+            Self::Day(cd) => format!("FREQ=DAILY;INTERVAL={}", cd.amount * cd.coefficient),
+            Self::WeeklyOn(mask, cd) => format!(
+                "FREQ=WEEKLY;INTERVAL={};BYDAY={}",
+                cd.amount * cd.coefficient,
+                mask
+            ),
+        }
+    }
+
+    /// Parses an iCalendar recurrence rule (RFC 5545) into an `Epoch`.
+    ///
+    /// Only `FREQ`, `INTERVAL`, `BYDAY` (weekly rules), `BYMONTHDAY=-1` (monthly
+    /// rules) and `COUNT` are understood; the interval becomes the epoch amount
+    /// with a coefficient of 1. A daily rule with `COUNT=1` is parsed as
+    /// `SingleDay`. Other `COUNT` values are ignored here, see
+    /// [`BoundedEpoch::from_rrule`].
+    pub fn from_rrule(rule: &str) -> Result<Epoch, AppError> {
+        let parts = parse_rrule(rule)?;
+        let interval = parts.interval.unwrap_or(1);
+        let calendar_data = CalendarData::new(interval, 1);
+        match parts.freq {
+            "YEARLY" => Ok(Epoch::Year(calendar_data)),
+            "MONTHLY" if parts.bymonthday == Some("-1") => Ok(Epoch::MonthEnd(calendar_data)),
+            "MONTHLY" => Ok(Epoch::Month(calendar_data)),
+            "WEEKLY" => match parts.byday {
+                Some(byday) => Ok(Epoch::WeeklyOn(byday.parse()?, calendar_data)),
+                None => Ok(Epoch::Week(calendar_data)),
+            },
+            "DAILY" if interval == 1 && parts.count == Some(1) => Ok(Epoch::SingleDay),
+            "DAILY" => Ok(Epoch::Day(calendar_data)),
+            freq => Err(AppError::InvalidInputString(format!(
+                "Unsupported RRULE frequency: {}",
+                freq
+            ))),
+        }
+    }
+
+    /// Returns the `(unit, amount, coefficient)` tokens an epoch string is
+    /// understood as, without constructing the `Epoch`.
+    ///
+    /// The unit is returned as written (`"d"`, `"w"`, `"m"`, `"me"` or `"y"`)
+    /// and a missing coefficient is reported as 1. The whole string must match
+    /// the epoch format; weekday masks are not part of it and are rejected.
+    ///
+    /// The unit is a `&str` rather than a `char` because the month-end unit,
+    /// `"me"`, is two letters long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::Epoch;
+    ///
+    /// assert_eq!(Epoch::explain("3m4x").unwrap(), ("m", 3, 4));
+    /// assert_eq!(Epoch::explain("2w").unwrap(), ("w", 2, 1));
+    /// assert!(Epoch::explain("3q").is_err());
+    /// ```
+    pub fn explain(s: &str) -> Result<(&str, i64, i64), AppError> {
+        parse_epoch(s)
+    }
+
+    /// Parses a comma-separated list of epochs, e.g. `"1w1x,1m1x"`.
+    ///
+    /// Commas inside a weekday mask (`"1w1x[MO,FR]"`) do not split the list.
+    ///
+    /// # Returns
+    ///
+    /// The epochs in list order, or an `AppError::InvalidInputString` naming the
+    /// index and text of the first segment that is empty or fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{CalendarData, Epoch};
+    ///
+    /// let epochs = Epoch::parse_list("1w1x,1m1x").unwrap();
+    /// assert_eq!(
+    ///     epochs,
+    ///     vec![
+    ///         Epoch::Week(CalendarData::new(1, 1)),
+    ///         Epoch::Month(CalendarData::new(1, 1)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn parse_list(s: &str) -> Result<Vec<Epoch>, AppError> {
+        let mut segments = Vec::new();
+        let (mut depth, mut from) = (0, 0);
+        for (index, c) in s.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    segments.push(&s[from..index]);
+                    from = index + 1;
+                }
+                _ => {}
+            }
+        }
+        segments.push(&s[from..]);
+
+        segments
+            .into_iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                let segment = segment.trim();
+                if segment.is_empty() {
+                    return Err(AppError::InvalidInputString(format!(
+                        "Empty epoch at segment {}",
+                        index
+                    )));
+                }
+                segment.parse().map_err(|err| {
+                    AppError::InvalidInputString(format!(
+                        "Invalid epoch at segment {} ({:?}): {}",
+                        index, segment, err
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+/// An `Epoch` with an optional cap on the number of occurrences, the equivalent
+/// of an iCalendar `COUNT`.
+///
+/// Occurrences stop after `max_occurrences` even if the `until` boundary has
+/// not been reached yet.
+#[derive(Debug, Copy, Clone, PartialEq, PersistentEmbedded)]
+pub struct BoundedEpoch {
+    pub epoch: Epoch,
+    pub max_occurrences: Option<u32>,
+}
+
+impl BoundedEpoch {
+    pub fn new(epoch: Epoch, max_occurrences: Option<u32>) -> Self {
+        Self {
+            epoch,
+            max_occurrences,
+        }
+    }
+
+    /// Returns a lazy iterator over the occurrences in `[start, until)`, yielding
+    /// at most `max_occurrences` items.
+    pub fn occurrences(
+        &self,
+        start: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> impl Iterator<Item = DateTime<Utc>> {
+        let cap = self.max_occurrences.map_or(usize::MAX, |max| max as usize);
+        self.epoch.occurrences(start, until).take(cap)
+    }
+
+    /// Returns the first occurrence strictly after `after`, or `None` once the
+    /// cap has been used up.
+    pub fn next_occurrence(
+        &self,
+        start: DateTime<Utc>,
+        after: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let n = self.epoch.next_occurrence_index(start, after)?;
+        match self.max_occurrences {
+            Some(max) if n >= max => None,
+            _ => self.epoch.nth_occurrence(start, n),
+        }
+    }
+
+    /// Converts the bounded epoch into an iCalendar recurrence rule, appending
+    /// `COUNT` when a cap is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::{BoundedEpoch, CalendarData, Epoch};
+    ///
+    /// let bounded = BoundedEpoch::new(Epoch::Month(CalendarData::new(1, 1)), Some(12));
+    /// assert_eq!(bounded.to_rrule(), "FREQ=MONTHLY;INTERVAL=1;COUNT=12");
+    /// ```
+    pub fn to_rrule(&self) -> String {
+        match (self.epoch, self.max_occurrences) {
+            (Epoch::SingleDay, _) | (_, None) => self.epoch.to_rrule(),
+            (epoch, Some(max)) => format!("{};COUNT={}", epoch.to_rrule(), max),
+        }
+    }
+
+    /// Parses an iCalendar recurrence rule, keeping its `COUNT` as the cap.
+    pub fn from_rrule(rule: &str) -> Result<BoundedEpoch, AppError> {
+        let epoch = Epoch::from_rrule(rule)?;
+        let max_occurrences = match epoch {
+            Epoch::SingleDay => None,
+            _ => parse_rrule(rule)?.count,
+        };
+        Ok(BoundedEpoch::new(epoch, max_occurrences))
+    }
+}
+
+/// The recurrence rule parts understood by `Epoch` and `BoundedEpoch`.
+struct RRuleParts<'a> {
+    freq: &'a str,
+    interval: Option<i64>,
+    count: Option<u32>,
+    byday: Option<&'a str>,
+    bymonthday: Option<&'a str>,
 }
 
-fn parse_epoch(text: &str) -> (&str, i64, i64) {
-    match RE_EPOCH.captures(text) {
-        Some(c) => (
-            c.get(3).map_or("d", |unit| unit.as_str()),
-            c.get(2).map_or(1, |a| a.as_str().parse::<i64>().unwrap()),
-            c.get(5).map_or(1, |r| r.as_str().parse::<i64>().unwrap()),
-        ),
-        None => ("d", 1, 1),
+fn parse_rrule(rule: &str) -> Result<RRuleParts<'_>, AppError> {
+    let rule = rule.trim();
+    let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+
+    let mut freq = None;
+    let mut interval = None;
+    let mut count = None;
+    let mut byday = None;
+    let mut bymonthday = None;
+    for part in rule.split(';').filter(|part| !part.is_empty()) {
+        let (key, value) = part.split_once('=').ok_or_else(|| {
+            AppError::InvalidInputString(format!("Malformed RRULE part: {}", part))
+        })?;
+        match key {
+            "FREQ" => freq = Some(value),
+            "INTERVAL" => {
+                let parsed = value
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|interval| *interval > 0)
+                    .ok_or_else(|| {
+                        AppError::ParseError(format!("Invalid RRULE interval: {}", value))
+                    })?;
+                interval = Some(parsed);
+            }
+            "COUNT" => {
+                let parsed = value
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|count| *count > 0)
+                    .ok_or_else(|| {
+                        AppError::ParseError(format!("Invalid RRULE count: {}", value))
+                    })?;
+                count = Some(parsed);
+            }
+            "BYDAY" => byday = Some(value),
+            "BYMONTHDAY" => bymonthday = Some(value),
+            _ => {}
+        }
     }
+
+    let freq =
+        freq.ok_or_else(|| AppError::InvalidInputString("RRULE is missing FREQ".to_string()))?;
+    Ok(RRuleParts {
+        freq,
+        interval,
+        count,
+        byday,
+        bymonthday,
+    })
+}
+
+/// Adds `months` calendar months to `datetime`, clamping the day to the last
+/// valid day of the target month.
+fn add_months(datetime: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    use chrono::{Datelike, NaiveDate};
+
+    let date = datetime.date();
+    let total_months = (date.year() as i64 * 12 + date.month0() as i64).checked_add(months)?;
+    let year = i32::try_from(total_months.div_euclid(12)).ok()?;
+    let month = total_months.rem_euclid(12) as u32 + 1;
+    let day = date.day().min(days_in_month(year, month)?);
+    NaiveDate::from_ymd_opt(year, month, day).map(|date| date.and_time(datetime.time()))
+}
+
+/// Moves `datetime` to the last day of its month, keeping the time of day.
+fn month_end(datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+    use chrono::Datelike;
+
+    let date = datetime.date();
+    date.with_day(days_in_month(date.year(), date.month())?)
+        .map(|date| date.and_time(datetime.time()))
+}
+
+/// Returns the number of days in the given month of the given year.
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    use chrono::{Datelike, NaiveDate};
+
+    let (next_year, next_month) = if month == 12 {
+        (year.checked_add(1)?, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|first| first.pred_opt())
+        .map(|last| last.day())
+}
+
+/// Splits an epoch string into its `(unit, amount, coefficient)` tokens.
+///
+/// The whole string must match the epoch grammar; empty input, unknown units
+/// and trailing characters are rejected. A missing coefficient defaults to 1,
+/// whereas a coefficient explicitly given as zero (e.g. `"3m0x"`) is rejected.
+fn parse_epoch(text: &str) -> Result<(&str, i64, i64), AppError> {
+    let parse_number = |token: &str| {
+        token
+            .parse::<i64>()
+            .map_err(|_| AppError::ParseError(format!("Failed to parse epoch number: {}", token)))
+    };
+
+    let c = RE_EPOCH
+        .captures(text)
+        .filter(|c| {
+            c.get(0)
+                .is_some_and(|m| m.start() == 0 && m.end() == text.len())
+        })
+        .ok_or_else(|| {
+            tracing::error!("Invalid epoch format: {:?}", text);
+            AppError::InvalidInputString(format!("Invalid epoch format: {:?}", text))
+        })?;
+    let unit = c.get(3).map_or("d", |unit| unit.as_str());
+    let amount = c.get(2).map_or(Ok(1), |a| parse_number(a.as_str()))?;
+    let coefficient = c.get(5).map_or(Ok(1), |r| parse_number(r.as_str()))?;
+    if amount == 0 || coefficient == 0 {
+        return Err(AppError::InvalidInputString(format!(
+            "Epoch amount and coefficient must be greater than zero: {}",
+            text
+        )));
+    }
+    Ok((unit, amount, coefficient))
 }
 
 impl FromStr for Epoch {
@@ -269,8 +1228,20 @@ impl FromStr for Epoch {
     /// let epoch = Epoch::from_str("3m4x").unwrap();
     /// assert_eq!(epoch, Epoch::Month(CalendarData { amount: 3, coefficient: 4 }));
     /// ```
+    ///
+    /// A weekly epoch may be followed by a bracketed weekday mask, e.g.
+    /// `"1w1x[MO,WE,FR]"`, to recur on each of those weekdays.
     fn from_str(s: &str) -> Result<Epoch, AppError> {
-        let (unit, amount, coefficient) = parse_epoch(s);
+        if let Some((head, weekdays)) = s.strip_suffix(']').and_then(|rest| rest.split_once('[')) {
+            return match head.parse::<Epoch>()? {
+                Epoch::Week(calendar_data) => Ok(Epoch::WeeklyOn(weekdays.parse()?, calendar_data)),
+                _ => Err(AppError::InvalidInputString(
+                    "Weekday masks are only valid for weekly epochs".to_string(),
+                )),
+            };
+        }
+
+        let (unit, amount, coefficient) = parse_epoch(s)?;
         match unit {
             "y" => Ok(Epoch::Year(CalendarData {
                 amount,
@@ -280,6 +1251,10 @@ impl FromStr for Epoch {
                 amount,
                 coefficient,
             })),
+            "me" => Ok(Epoch::MonthEnd(CalendarData {
+                amount,
+                coefficient,
+            })),
             "w" => Ok(Epoch::Week(CalendarData {
                 amount,
                 coefficient,
@@ -306,9 +1281,700 @@ impl std::fmt::Display for Epoch {
         match self {
             Self::Year(cd) => write!(f, "{}y{}x", cd.amount, cd.coefficient),
             Self::Month(cd) => write!(f, "{}m{}x", cd.amount, cd.coefficient),
+            Self::MonthEnd(cd) => write!(f, "{}me{}x", cd.amount, cd.coefficient),
             Self::Week(cd) => write!(f, "{}w{}x", cd.amount, cd.coefficient),
             Self::Day(cd) => write!(f, "{}d{}x", cd.amount, cd.coefficient),
+            Self::WeeklyOn(mask, cd) => write!(f, "{}w{}x[{}]", cd.amount, cd.coefficient, mask),
             Self::SingleDay => write!(f, "1d1x"),
         }
     }
 }
+
+/// Serializes as the compact string form, e.g. `"3m4x"`, using `Display`.
+///
+/// `SingleDay` and `Day(1, 1)` share the `"1d1x"` form, which deserializes as
+/// `SingleDay`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Epoch {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Epoch {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_from_str_coefficient_defaults_to_one() {
+        let expected = Epoch::Month(CalendarData::new(3, 1));
+        assert_eq!(Epoch::from_str("3m"), Ok(expected));
+        assert_eq!(Epoch::from_str("3m1x"), Ok(expected));
+    }
+
+    #[test]
+    fn test_from_str_rejects_zero_coefficient() {
+        assert!(matches!(
+            Epoch::from_str("3m0x"),
+            Err(AppError::InvalidInputString(_))
+        ));
+        assert!(Epoch::from_str("2w00x").is_err());
+    }
+
+    #[test]
+    fn test_to_duration_checked_overflow() {
+        let epoch = Epoch::Year(CalendarData::new(i64::MAX / 2, 2));
+        assert!(matches!(
+            epoch.to_duration_checked(),
+            Err(AppError::Overflow(_))
+        ));
+        assert_eq!(
+            Epoch::Month(CalendarData::new(3, 4)).to_duration_checked(),
+            Ok(chrono::Duration::try_days(360).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_iso_week_occurrences_land_on_mondays() {
+        use chrono::Datelike;
+
+        let epoch = Epoch::Week(CalendarData::new(1, 1));
+        let start = Utc.with_ymd_and_hms(2024, 1, 3, 10, 0, 0).unwrap(); // Wednesday
+        let until = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+
+        let occurrences: Vec<_> = epoch.iso_week_occurrences(start, until).collect();
+        assert_eq!(occurrences.len(), 4);
+        for (occurrence, iso_week) in occurrences.iter().zip(2..) {
+            assert_eq!(occurrence.weekday(), Weekday::Mon);
+            assert_eq!(occurrence.time(), NaiveTime::MIN);
+            assert_eq!(occurrence.iso_week().week(), iso_week);
+        }
+
+        let biweekly = Epoch::Week(CalendarData::new(2, 1));
+        let monday = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(biweekly.iso_week_occurrences(monday, until).count(), 3);
+        assert_eq!(
+            Epoch::Month(CalendarData::new(1, 1))
+                .iso_week_occurrences(start, until)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_snap_to_occurrence_weekly() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let weekly = Epoch::Week(CalendarData::new(1, 1));
+        let occurrence = Utc.with_ymd_and_hms(2024, 1, 8, 9, 0, 0).unwrap();
+
+        // Two days after an occurrence snaps back to it.
+        let target = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        assert_eq!(weekly.snap_to_occurrence(target, start), occurrence);
+        // Five days after snaps forward to the next one.
+        let target = Utc.with_ymd_and_hms(2024, 1, 13, 9, 0, 0).unwrap();
+        assert_eq!(
+            weekly.snap_to_occurrence(target, start),
+            Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap()
+        );
+        assert_eq!(weekly.snap_to_occurrence(occurrence, start), occurrence);
+        let before = Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap();
+        assert_eq!(weekly.snap_to_occurrence(before, start), start);
+        let later = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(Epoch::SingleDay.snap_to_occurrence(later, start), start);
+    }
+
+    #[test]
+    fn test_period_containing_before_first_month_end() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let epoch = Epoch::MonthEnd(CalendarData::new(1, 1));
+        let at = Utc.with_ymd_and_hms(2024, 1, 20, 0, 0, 0).unwrap();
+        assert_eq!(epoch.period_containing(start, at), None);
+        assert_eq!(
+            epoch.snap_to_occurrence(at, start),
+            Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_next_dates_monthly() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 17, 30, 0).unwrap();
+        let epoch = Epoch::Month(CalendarData::new(1, 1));
+        assert_eq!(
+            epoch.next_dates(start, 3),
+            vec![
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            ]
+        );
+        assert_eq!(Epoch::SingleDay.next_dates(start, 3).len(), 1);
+    }
+
+    #[test]
+    fn test_from_str_rejects_malformed_input() {
+        for input in ["", "garbage", "3mfoo", "3m4xfoo", " 3m", "0d", "5m0x"] {
+            assert!(
+                matches!(Epoch::from_str(input), Err(AppError::InvalidInputString(_))),
+                "{:?}",
+                input
+            );
+        }
+        assert_eq!(Epoch::from_str("1d"), Ok(Epoch::SingleDay));
+        assert_eq!(
+            Epoch::from_str("3m4x"),
+            Ok(Epoch::Month(CalendarData::new(3, 4)))
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(Epoch::explain("3m4x"), Ok(("m", 3, 4)));
+        assert_eq!(Epoch::explain("1me2x"), Ok(("me", 1, 2)));
+        assert_eq!(Epoch::explain("10d"), Ok(("d", 10, 1)));
+    }
+
+    #[test]
+    fn test_explain_malformed() {
+        for input in ["", "3q", "0m", "3m4", "x3m4x", "3m4x ", "1w1x[MO]"] {
+            assert!(
+                matches!(Epoch::explain(input), Err(AppError::InvalidInputString(_))),
+                "{:?}",
+                input
+            );
+        }
+        assert!(matches!(
+            Epoch::explain("3m0x"),
+            Err(AppError::InvalidInputString(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            Epoch::parse_list("1w1x, 1m1x"),
+            Ok(vec![
+                Epoch::Week(CalendarData::new(1, 1)),
+                Epoch::Month(CalendarData::new(1, 1)),
+            ])
+        );
+        assert_eq!(
+            Epoch::parse_list("1w1x[MO,FR],2y"),
+            Ok(vec![
+                Epoch::WeeklyOn(
+                    WeeklyMask::from_weekdays(&[Weekday::Mon, Weekday::Fri]),
+                    CalendarData::new(1, 1)
+                ),
+                Epoch::Year(CalendarData::new(2, 1)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_list_malformed_segment() {
+        let err = Epoch::parse_list("1w1x,1m0x").unwrap_err();
+        assert!(matches!(
+            &err,
+            AppError::InvalidInputString(message) if message.contains("segment 1 (\"1m0x\")")
+        ));
+        assert!(Epoch::parse_list("1w1x,,1m1x").is_err());
+        assert!(Epoch::parse_list("1m1x[MO]").is_err());
+    }
+
+    #[test]
+    fn test_closest_send_frequency() {
+        let cases = [
+            (Epoch::SingleDay, SendFrequency::Daily),
+            (Epoch::Day(CalendarData::new(3, 1)), SendFrequency::Daily),
+            (Epoch::Day(CalendarData::new(5, 1)), SendFrequency::Weekly),
+            (Epoch::Week(CalendarData::new(1, 1)), SendFrequency::Weekly),
+            (
+                Epoch::Week(CalendarData::new(2, 1)),
+                SendFrequency::BiWeekly,
+            ),
+            (
+                Epoch::Month(CalendarData::new(1, 1)),
+                SendFrequency::Monthly,
+            ),
+            (
+                Epoch::Month(CalendarData::new(3, 1)),
+                SendFrequency::Quarterly,
+            ),
+            (
+                Epoch::Year(CalendarData::new(1, 1)),
+                SendFrequency::Quarterly,
+            ),
+        ];
+        for (epoch, frequency) in cases {
+            assert_eq!(epoch.closest_send_frequency(), frequency, "{}", epoch);
+        }
+    }
+
+    fn naive(year: i32, month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .expect("valid date")
+            .and_time(NaiveTime::MIN)
+    }
+
+    #[test]
+    fn test_calculate_days_since_clamps_month_end() {
+        let monthly = Epoch::Month(CalendarData::new(1, 1));
+        // Jan 31 -> Feb 29 in a leap year, Feb 28 otherwise.
+        assert_eq!(monthly.calculate_days_since(naive(2024, 1, 31)), 29);
+        assert_eq!(monthly.calculate_days_since(naive(2023, 1, 31)), 28);
+        // Mar 31 -> Apr 30.
+        assert_eq!(monthly.calculate_days_since(naive(2024, 3, 31)), 30);
+
+        // 3m4x is 12 months: Aug 31 2023 -> Aug 31 2024.
+        let yearly_in_months = Epoch::Month(CalendarData::new(3, 4));
+        assert_eq!(
+            yearly_in_months.calculate_days_since(naive(2023, 8, 31)),
+            366
+        );
+        // 3m2x is 6 months: Aug 31 -> Feb 29.
+        let half_year = Epoch::Month(CalendarData::new(3, 2));
+        assert_eq!(half_year.calculate_days_since(naive(2023, 8, 31)), 182);
+    }
+
+    #[test]
+    fn test_occurrences_from_clamps_month_end() {
+        let monthly = Epoch::Month(CalendarData::new(1, 1));
+        let walk: Vec<_> = monthly
+            .occurrences_from(naive(2023, 1, 31))
+            .take(3)
+            .collect();
+        assert_eq!(
+            walk,
+            vec![naive(2023, 1, 31), naive(2023, 2, 28), naive(2023, 3, 31)]
+        );
+
+        // Each step agrees with `calculate_days_since` from the previous date.
+        let quarterly = Epoch::Month(CalendarData::new(3, 1));
+        let walk: Vec<_> = quarterly
+            .occurrences_from(naive(2023, 11, 30))
+            .take(2)
+            .collect();
+        assert_eq!(
+            walk[1].signed_duration_since(walk[0]).num_days(),
+            quarterly.calculate_days_since(walk[0])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let cases = [
+            (Epoch::Month(CalendarData::new(3, 4)), r#""3m4x""#),
+            (Epoch::SingleDay, r#""1d1x""#),
+            (Epoch::Day(CalendarData::new(2, 1)), r#""2d1x""#),
+            (
+                Epoch::WeeklyOn("MO,FR".parse().unwrap(), CalendarData::new(1, 1)),
+                r#""1w1x[MO,FR]""#,
+            ),
+        ];
+        for (epoch, json) in cases {
+            assert_eq!(serde_json::to_string(&epoch).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Epoch>(json).unwrap(), epoch);
+        }
+        assert!(serde_json::from_str::<Epoch>(r#""3q""#).is_err());
+
+        let calendar_data = CalendarData::new(3, 4);
+        let json = serde_json::to_string(&calendar_data).unwrap();
+        assert_eq!(json, r#"{"amount":3,"coefficient":4}"#);
+        assert_eq!(
+            serde_json::from_str::<CalendarData>(&json).unwrap(),
+            calendar_data
+        );
+    }
+
+    #[test]
+    fn test_infer() {
+        let at = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 9, 0, 0).unwrap();
+        let with = |amount| CalendarData::new(amount, 1);
+
+        assert_eq!(
+            Epoch::infer(at(2024, 3, 4), at(2024, 3, 11)),
+            Epoch::Week(with(1))
+        );
+        assert_eq!(
+            Epoch::infer(at(2024, 3, 1), at(2024, 4, 1)),
+            Epoch::Month(with(1))
+        );
+        // 31 days that do not line up with a calendar month still read monthly.
+        assert_eq!(
+            Epoch::infer(at(2024, 4, 1), at(2024, 5, 2)),
+            Epoch::Month(with(1))
+        );
+        assert_eq!(
+            Epoch::infer(at(2024, 1, 1), at(2024, 1, 29)),
+            Epoch::Week(with(4))
+        );
+        assert_eq!(
+            Epoch::infer(at(2024, 1, 31), at(2024, 4, 30)),
+            Epoch::Month(with(3))
+        );
+        assert_eq!(
+            Epoch::infer(at(2024, 2, 29), at(2025, 2, 28)),
+            Epoch::Year(with(1))
+        );
+        assert_eq!(
+            Epoch::infer(at(2024, 1, 1), at(2024, 1, 4)),
+            Epoch::Day(with(3))
+        );
+        assert_eq!(
+            Epoch::infer(at(2024, 1, 11), at(2024, 1, 1)),
+            Epoch::Day(with(10))
+        );
+        assert_eq!(
+            Epoch::infer(at(2024, 1, 1), at(2024, 1, 1)),
+            Epoch::SingleDay
+        );
+    }
+
+    #[test]
+    fn test_to_duration_from_across_leap_year() {
+        let yearly = Epoch::Year(CalendarData::new(1, 1));
+        let since = naive(2024, 1, 1);
+        assert_eq!(yearly.to_duration(), Duration::try_days(365).unwrap());
+        assert_eq!(
+            yearly.to_duration_from(since),
+            Duration::try_days(366).unwrap()
+        );
+        assert_eq!(
+            yearly.to_duration_from(naive(2025, 1, 1)),
+            Duration::try_days(365).unwrap()
+        );
+        assert_eq!(
+            yearly.to_duration_from(since).num_days(),
+            yearly.calculate_days_since(since)
+        );
+    }
+
+    #[test]
+    fn test_to_duration_from_31_day_month() {
+        let monthly = Epoch::Month(CalendarData::new(1, 1));
+        assert_eq!(monthly.to_duration(), Duration::try_days(30).unwrap());
+        assert_eq!(
+            monthly.to_duration_from(naive(2024, 3, 1)),
+            Duration::try_days(31).unwrap()
+        );
+        assert_eq!(
+            monthly.to_duration_from(naive(2024, 2, 1)),
+            Duration::try_days(29).unwrap()
+        );
+
+        // Fixed-length epochs need no anchor.
+        let weekly = Epoch::Week(CalendarData::new(2, 1));
+        assert_eq!(
+            weekly.to_duration_from(naive(2024, 3, 1)),
+            weekly.to_duration()
+        );
+    }
+
+    #[test]
+    fn test_collect_occurrences_daily_fast_path() {
+        let start = Utc.with_ymd_and_hms(2000, 1, 1, 9, 0, 0).unwrap();
+        let daily = Epoch::Day(CalendarData::new(1, 1));
+        let until = start + Duration::try_days(10_000).unwrap();
+
+        let occurrences = daily.collect_occurrences(start, until);
+        assert_eq!(occurrences.len(), 10_000);
+        assert_eq!(occurrences.capacity(), 10_000);
+        assert_eq!(
+            occurrences,
+            daily.occurrences(start, until).collect::<Vec<_>>()
+        );
+        assert_eq!(occurrences[9_999], until - Duration::try_days(1).unwrap());
+
+        // One second past a boundary includes it.
+        let occurrences =
+            daily.collect_occurrences(start, until + Duration::try_seconds(1).unwrap());
+        assert_eq!(occurrences.len(), 10_001);
+        assert!(daily.collect_occurrences(until, start).is_empty());
+    }
+
+    #[test]
+    fn test_collect_occurrences_matches_iterator() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 31, 9, 0, 0).unwrap();
+        let until = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        for epoch in [
+            Epoch::SingleDay,
+            Epoch::Week(CalendarData::new(2, 1)),
+            Epoch::Month(CalendarData::new(1, 1)),
+        ] {
+            assert_eq!(
+                epoch.collect_occurrences(start, until),
+                epoch.occurrences(start, until).collect::<Vec<_>>(),
+                "{:?}",
+                epoch
+            );
+        }
+    }
+
+    #[test]
+    fn test_occurrences_from_single_day() {
+        let occurrences: Vec<_> = Epoch::SingleDay
+            .occurrences_from(naive(2024, 1, 1))
+            .collect();
+        assert_eq!(occurrences, vec![naive(2024, 1, 1)]);
+    }
+
+    #[test]
+    fn test_calculate_days_since_year_from_leap_day() {
+        let yearly = Epoch::Year(CalendarData::new(1, 1));
+        // Feb 29 2024 -> Feb 28 2025.
+        assert_eq!(yearly.calculate_days_since(naive(2024, 2, 29)), 365);
+        assert_eq!(yearly.calculate_days_since(naive(2023, 3, 1)), 366);
+    }
+
+    #[test]
+    fn test_year_from_leap_day_clamps_to_feb_28() {
+        let yearly = Epoch::Year(CalendarData::new(1, 1));
+        let start = naive(2024, 2, 29);
+
+        assert_eq!(
+            yearly.to_duration_from(start),
+            Duration::try_days(365).unwrap()
+        );
+        assert_eq!(
+            yearly.nth_occurrence(start.and_utc(), 1),
+            Some(naive(2025, 2, 28).and_utc())
+        );
+        // Occurrences are counted from the start, so leap years get Feb 29 back.
+        assert_eq!(
+            yearly.nth_occurrence(start.and_utc(), 4),
+            Some(naive(2028, 2, 29).and_utc())
+        );
+    }
+
+    #[test]
+    fn test_approx_days_per_period() {
+        assert_eq!(
+            Epoch::Month(CalendarData::new(1, 1)).approx_days_per_period(),
+            30
+        );
+        assert_eq!(
+            Epoch::Week(CalendarData::new(1, 1)).approx_days_per_period(),
+            7
+        );
+        assert_eq!(
+            Epoch::Year(CalendarData::new(1, 1)).approx_days_per_period(),
+            365
+        );
+        assert_eq!(
+            Epoch::Day(CalendarData::new(3, 2)).approx_days_per_period(),
+            6
+        );
+        assert_eq!(Epoch::SingleDay.approx_days_per_period(), 1);
+    }
+
+    #[test]
+    fn test_month_end_occurrences() {
+        let epoch = Epoch::MonthEnd(CalendarData::new(1, 1));
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+        let until = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        let days: Vec<_> = epoch
+            .occurrences(start, until)
+            .map(|occurrence| occurrence.date_naive().to_string())
+            .collect();
+        assert_eq!(days.len(), 12);
+        assert_eq!(days[0], "2024-01-31");
+        assert_eq!(days[1], "2024-02-29"); // leap February
+        assert_eq!(days[3], "2024-04-30");
+        assert_eq!(days[11], "2024-12-31");
+
+        let non_leap = Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            epoch.nth_occurrence(non_leap, 0),
+            Some(Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_month_end_calculate_days_since_and_strings() {
+        let epoch = Epoch::MonthEnd(CalendarData::new(1, 1));
+        let since = NaiveDateTime::new(
+            chrono::NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            NaiveTime::MIN,
+        );
+        assert_eq!(epoch.calculate_days_since(since), 30); // to April 30
+        let since = NaiveDateTime::new(
+            chrono::NaiveDate::from_ymd_opt(2024, 11, 30).unwrap(),
+            NaiveTime::MIN,
+        );
+        assert_eq!(epoch.calculate_days_since(since), 31); // to December 31
+
+        assert_eq!(epoch.to_string(), "1me1x");
+        assert_eq!(Epoch::from_str("1me1x"), Ok(epoch));
+        assert_eq!(
+            Epoch::from_str("2me"),
+            Ok(Epoch::MonthEnd(CalendarData::new(2, 1)))
+        );
+        assert_eq!(epoch.to_rrule(), "FREQ=MONTHLY;INTERVAL=1;BYMONTHDAY=-1");
+        assert_eq!(Epoch::from_rrule(&epoch.to_rrule()), Ok(epoch));
+    }
+
+    #[test]
+    fn test_weekly_mask_occurrences_over_two_weeks() {
+        let mask = WeeklyMask::from_weekdays(&[Weekday::Mon, Weekday::Wed, Weekday::Fri]);
+        let epoch = Epoch::WeeklyOn(mask, CalendarData::new(1, 1));
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(); // Monday
+        let until = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+
+        let days: Vec<_> = epoch
+            .occurrences(start, until)
+            .map(|occurrence| occurrence.date_naive().to_string())
+            .collect();
+        assert_eq!(
+            days,
+            vec![
+                "2024-01-01",
+                "2024-01-03",
+                "2024-01-05",
+                "2024-01-08",
+                "2024-01-10",
+                "2024-01-12"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekly_mask_skips_weekdays_before_start() {
+        let mask = WeeklyMask::from_weekdays(&[Weekday::Mon, Weekday::Thu]);
+        let epoch = Epoch::WeeklyOn(mask, CalendarData::new(2, 1));
+        let start = Utc.with_ymd_and_hms(2024, 1, 3, 9, 0, 0).unwrap(); // Wednesday
+
+        assert_eq!(
+            epoch.nth_occurrence(start, 0),
+            Some(Utc.with_ymd_and_hms(2024, 1, 4, 9, 0, 0).unwrap())
+        );
+        assert_eq!(
+            epoch.nth_occurrence(start, 1),
+            Some(Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap())
+        );
+        assert_eq!(
+            epoch.next_occurrence(start, Utc.with_ymd_and_hms(2024, 1, 4, 9, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_weekly_mask_rrule_and_string_round_trip() {
+        let mask = WeeklyMask::from_weekdays(&[Weekday::Fri, Weekday::Mon, Weekday::Wed]);
+        let epoch = Epoch::WeeklyOn(mask, CalendarData::new(1, 1));
+
+        assert_eq!(epoch.to_rrule(), "FREQ=WEEKLY;INTERVAL=1;BYDAY=MO,WE,FR");
+        assert_eq!(Epoch::from_rrule(&epoch.to_rrule()), Ok(epoch));
+        assert_eq!(epoch.to_string(), "1w1x[MO,WE,FR]");
+        assert_eq!(Epoch::from_str(&epoch.to_string()), Ok(epoch));
+        assert!(Epoch::from_str("1m1x[MO]").is_err());
+        assert!(Epoch::from_str("1w1x[XX]").is_err());
+    }
+
+    #[test]
+    fn test_bounded_epoch_caps_weekly_occurrences() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let until = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let bounded = BoundedEpoch::new(Epoch::Week(CalendarData::new(1, 1)), Some(4));
+
+        let occurrences: Vec<_> = bounded.occurrences(start, until).collect();
+        assert_eq!(
+            occurrences,
+            vec![
+                start,
+                Utc.with_ymd_and_hms(2024, 1, 8, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 22, 9, 0, 0).unwrap(),
+            ]
+        );
+        assert_eq!(
+            bounded.next_occurrence(start, occurrences[2]),
+            Some(occurrences[3])
+        );
+        assert_eq!(bounded.next_occurrence(start, occurrences[3]), None);
+    }
+
+    #[test]
+    fn test_bounded_epoch_rrule_round_trip() {
+        let bounded = BoundedEpoch::new(Epoch::Week(CalendarData::new(1, 1)), Some(4));
+        assert_eq!(bounded.to_rrule(), "FREQ=WEEKLY;INTERVAL=1;COUNT=4");
+        assert_eq!(BoundedEpoch::from_rrule(&bounded.to_rrule()), Ok(bounded));
+
+        let unbounded = BoundedEpoch::from_rrule("RRULE:FREQ=MONTHLY;INTERVAL=3").unwrap();
+        assert_eq!(
+            unbounded,
+            BoundedEpoch::new(Epoch::Month(CalendarData::new(3, 1)), None)
+        );
+        assert_eq!(
+            BoundedEpoch::from_rrule("FREQ=DAILY;COUNT=1")
+                .unwrap()
+                .epoch,
+            Epoch::SingleDay
+        );
+        assert!(BoundedEpoch::from_rrule("FREQ=HOURLY;COUNT=4").is_err());
+        assert!(BoundedEpoch::from_rrule("FREQ=WEEKLY;COUNT=0").is_err());
+    }
+
+    #[test]
+    fn test_next_occurrence_over_long_spans() {
+        let start = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+        let monthly = Epoch::Month(CalendarData::new(1, 1));
+        // 30-day months overestimate 17 years by 3 periods.
+        assert_eq!(
+            monthly.next_occurrence(start, Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2017, 2, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            monthly.next_occurrence(start, Utc.with_ymd_and_hms(2000, 1, 15, 0, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2000, 2, 1, 0, 0, 0).unwrap())
+        );
+        for n in 0..300 {
+            let occurrence = monthly.nth_occurrence(start, n).unwrap();
+            assert_eq!(
+                monthly.next_occurrence(start, occurrence),
+                monthly.nth_occurrence(start, n + 1)
+            );
+        }
+
+        let month_end = Epoch::MonthEnd(CalendarData::new(1, 1));
+        assert_eq!(
+            month_end.next_occurrence(start, Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2017, 1, 31, 0, 0, 0).unwrap())
+        );
+        for n in 0..300 {
+            let occurrence = month_end.nth_occurrence(start, n).unwrap();
+            assert_eq!(
+                month_end.next_occurrence(start, occurrence),
+                month_end.nth_occurrence(start, n + 1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_next_occurrence_zero_period() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let zero = Epoch::Day(CalendarData::new(0, 1));
+        let day = chrono::Duration::try_days(1).unwrap();
+        assert_eq!(zero.next_occurrence(start, start + day), None);
+        assert_eq!(zero.next_occurrence(start, start - day), Some(start));
+    }
+
+    #[test]
+    fn test_next_occurrence_overlong_period() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let overlong: Epoch = "9999999999999y".parse().unwrap();
+        let day = chrono::Duration::try_days(1).unwrap();
+        assert!(overlong.to_duration_checked().is_err());
+        assert_eq!(overlong.next_occurrence(start, start + day), None);
+        assert_eq!(overlong.period_containing(start, start + day), None);
+    }
+}
@@ -1,9 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::error::AppError;
 use crate::models::{decimal::Money, epoch::Epoch, signal::SignalTrigger, uid::GlobalId};
 use crate::utils;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, Utc};
 use log::{info, warn};
 use structsy::derive::PersistentEmbedded;
 
@@ -42,6 +43,7 @@ pub struct Event {
     pub start_datetime: DateTime<Utc>, // Start date for recorded event
     pub end_datetime: DateTime<Utc>,   // End date for recorded event
     created_at: DateTime<Utc>,         // Date created
+    last_updated: DateTime<Utc>,       // Date last updated
 }
 
 impl Event {
@@ -67,13 +69,593 @@ impl Event {
             start_datetime,
             end_datetime,
             created_at,
+            last_updated: created_at,
         }
     }
+    /// Returns the first occurrence of the event strictly after `after`, or
+    /// `None` once the event has no occurrence left before `end_datetime`.
+    pub fn next_occurrence(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.epoch
+            .next_occurrence(self.start_datetime, after)
+            .filter(|occurrence| *occurrence < self.end_datetime)
+    }
+
+    /// Returns every occurrence of the event's epoch in
+    /// `[start_datetime, end_datetime)`, in order.
+    pub fn occurrences(&self) -> Vec<DateTime<Utc>> {
+        self.epoch
+            .occurrences(self.start_datetime, self.end_datetime)
+            .collect()
+    }
+
+    /// Returns the occurrence at which the running total of charges first
+    /// exceeds `budget`, or `None` if the event ends before the budget runs out.
+    ///
+    /// Each occurrence in `[start_datetime, end_datetime)` is charged `amount`.
+    pub fn budget_exhausted_at(&self, budget: Money) -> Option<DateTime<Utc>> {
+        let budget = budget.saturating_total_cents();
+        let charge = self.amount.saturating_total_cents();
+        let mut spent: i64 = 0;
+        self.epoch
+            .occurrences(self.start_datetime, self.end_datetime)
+            .find(|_| {
+                spent = spent.saturating_add(charge);
+                spent > budget
+            })
+    }
+
+    /// Returns the number of occurrences charged so far, i.e. the occurrences
+    /// at or before `now` that fall before `end_datetime`.
+    pub fn expected_occurrences(&self, now: DateTime<Utc>) -> i64 {
+        let until = self.end_datetime.min(now + Duration::nanoseconds(1));
+        self.epoch.occurrences(self.start_datetime, until).count() as i64
+    }
+
+    /// Returns the number of whole epoch periods completed between
+    /// `start_datetime` and `now`, counting no further than `end_datetime`.
+    ///
+    /// A period is complete once the next occurrence has been reached, so a
+    /// weekly event is two periods in exactly 14 days after it starts. Returns
+    /// 0 before the event starts.
+    pub fn elapsed_periods(&self, now: DateTime<Utc>) -> i64 {
+        let until = self.end_datetime.min(now) + Duration::nanoseconds(1);
+        let boundaries = self.epoch.occurrences(self.start_datetime, until).count() as i64;
+        boundaries.saturating_sub(1).max(0)
+    }
+
+    /// Returns what is left of a prepayment of `total_paid` at `now`, i.e.
+    /// `total_paid` minus `amount` for every elapsed period (see
+    /// `elapsed_periods`).
+    ///
+    /// The result goes negative once the event is overdrawn.
+    pub fn remaining_value(&self, now: DateTime<Utc>, total_paid: Money) -> Money {
+        let spent = self
+            .amount
+            .saturating_total_cents()
+            .saturating_mul(self.elapsed_periods(now));
+        total_paid - Money::from_cents(spent)
+    }
+
+    /// Compares the charges that should have happened by `now` against the
+    /// `actual` number recorded.
+    ///
+    /// # Returns
+    ///
+    /// `expected_occurrences(now) - actual`: positive when the event was
+    /// under-billed, negative when it was over-billed, zero when they match.
+    pub fn reconcile_charges(&self, now: DateTime<Utc>, actual: i64) -> i64 {
+        self.expected_occurrences(now).saturating_sub(actual)
+    }
+
+    /// Moves `start_datetime` forward to the first occurrence of the event's
+    /// epoch, counted from `anchor`, that is at or after the current start.
+    /// `end_datetime` is shifted by the same delta so the event keeps its length.
+    ///
+    /// The event is left unchanged if the epoch has no such occurrence.
+    pub fn align_start_to_epoch(&mut self, anchor: DateTime<Utc>) {
+        let aligned = if self.start_datetime <= anchor {
+            Some(anchor)
+        } else {
+            self.epoch
+                .period_containing(anchor, self.start_datetime)
+                .and_then(|(from, to)| {
+                    if from == self.start_datetime {
+                        Some(from)
+                    } else if let Epoch::SingleDay = self.epoch {
+                        None
+                    } else {
+                        Some(to)
+                    }
+                })
+        };
+
+        if let Some(aligned) = aligned {
+            let delta = aligned.signed_duration_since(self.start_datetime);
+            self.start_datetime = aligned;
+            self.end_datetime += delta;
+        }
+    }
+
+    /// Returns the number of whole days from `now` until `start_datetime`,
+    /// negative once the event has started.
+    ///
+    /// Partial days are truncated toward zero, so an event starting in 36 hours
+    /// is 1 day out.
+    pub fn days_until_start(&self, now: DateTime<Utc>) -> i64 {
+        self.start_datetime.signed_duration_since(now).num_days()
+    }
+
+    /// Returns true if the event has ended, i.e. `end_datetime` is at or
+    /// before `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.end_datetime <= now
+    }
+
+    /// Returns true if the event is running at `now`, i.e. `now` falls in
+    /// `[start_datetime, end_datetime)`.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.start_datetime <= now && !self.is_expired(now)
+    }
+
+    /// Returns the time left until `end_datetime`, or `None` once the event has
+    /// expired (see `is_expired`).
+    pub fn time_remaining(&self, now: DateTime<Utc>) -> Option<Duration> {
+        (!self.is_expired(now)).then(|| self.end_datetime.signed_duration_since(now))
+    }
+
+    /// Returns how far through its lifetime the event is at `now`, from 0.0 at
+    /// `start_datetime` to 1.0 at `end_datetime`, clamped at both ends.
+    ///
+    /// An event whose start and end are equal jumps from 0.0 to 1.0 at that
+    /// instant.
+    pub fn progress(&self, now: DateTime<Utc>) -> f64 {
+        let lifetime = self.end_datetime.signed_duration_since(self.start_datetime);
+        let elapsed = now.signed_duration_since(self.start_datetime);
+        if lifetime <= Duration::zero() {
+            return if elapsed < Duration::zero() { 0.0 } else { 1.0 };
+        }
+        let seconds = |duration: Duration| {
+            duration.num_seconds() as f64 + duration.subsec_nanos() as f64 / 1e9
+        };
+        (seconds(elapsed) / seconds(lifetime)).clamp(0.0, 1.0)
+    }
+
+    /// Returns the span of the event as Unix timestamps in seconds,
+    /// `(start_datetime, end_datetime)`.
+    pub fn timestamp_range(&self) -> (i64, i64) {
+        (
+            self.start_datetime.timestamp(),
+            self.end_datetime.timestamp(),
+        )
+    }
+
+    /// Returns the fraction of one epoch period that the event's span occupies,
+    /// clamped to `[0, 1]`; a 5-day event on a weekly epoch covers about 0.71.
+    ///
+    /// The period is the approximate `Epoch::to_duration_checked`. An epoch
+    /// whose period is zero, negative or overflows covers 0.0.
+    pub fn period_coverage(&self) -> f64 {
+        let period = match self.epoch.to_duration_checked() {
+            Ok(period) if period > Duration::zero() => period,
+            _ => return 0.0,
+        };
+        let span = self.end_datetime.signed_duration_since(self.start_datetime);
+        (span.num_seconds() as f64 / period.num_seconds() as f64).clamp(0.0, 1.0)
+    }
+
+    /// Returns a key ordering events by `start_datetime`, then by `title`, for
+    /// deterministic list rendering with `sort_by_key`.
+    pub fn sort_key(&self) -> (DateTime<Utc>, String) {
+        (self.start_datetime, self.title.clone())
+    }
+
+    /// Moves the event's end to `new_end`; occurrences are re-evaluated against
+    /// the new end from then on.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_end` - The new end date and time, which must be after `start_datetime`.
+    ///
+    /// # Returns
+    ///
+    /// `AppError::InvalidInputString` if `new_end` is not after `start_datetime`;
+    /// the event is left unchanged in that case.
+    pub fn set_end(&mut self, new_end: DateTime<Utc>) -> Result<(), AppError> {
+        if new_end <= self.start_datetime {
+            return Err(AppError::InvalidInputString(format!(
+                "end {} must be after start {}",
+                new_end.to_rfc3339(),
+                self.start_datetime.to_rfc3339()
+            )));
+        }
+        self.end_datetime = new_end;
+        self.last_updated = utils::get_current_datetime_utc();
+        Ok(())
+    }
+
+    /// Moves the event's start to midnight UTC of the same date, for day
+    /// granularity scheduling.
+    pub fn truncate_start_to_day(&mut self) {
+        self.start_datetime = self
+            .start_datetime
+            .date_naive()
+            .and_time(chrono::NaiveTime::MIN)
+            .and_utc();
+        self.last_updated = utils::get_current_datetime_utc();
+    }
+
+    /// Renders the event as an iCalendar `VEVENT` block (RFC 5545), with lines
+    /// separated by CRLF.
+    ///
+    /// The `UID` is the event's `GlobalId` display, and recurring epochs get an
+    /// `RRULE` bounded by `end_datetime`. Tags are exported as `CATEGORIES`.
+    pub fn to_vevent(&self) -> String {
+        let mut lines = vec![
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}@event-pulse", self.global_id()),
+            format!("DTSTAMP:{}", ical_datetime(self.created_at)),
+            format!("DTSTART:{}", ical_datetime(self.start_datetime)),
+            format!("SUMMARY:{}", ical_escape(&self.title)),
+        ];
+        if self.epoch != Epoch::SingleDay {
+            // UNTIL is inclusive, while occurrences stop before end_datetime.
+            let until = self.end_datetime - Duration::try_seconds(1).expect("1 second");
+            lines.push(format!(
+                "RRULE:{};UNTIL={}",
+                self.epoch.to_rrule(),
+                ical_datetime(until)
+            ));
+        }
+        if let Some(tags) = self.tags.as_ref().filter(|tags| !tags.is_empty()) {
+            let tags: Vec<String> = tags.iter().map(|tag| ical_escape(tag)).collect();
+            lines.push(format!("CATEGORIES:{}", tags.join(",")));
+        }
+        lines.push("END:VEVENT".to_string());
+        lines.join("\r\n")
+    }
+
+    /// Returns a copy of the event moved to start at `new_start`.
+    ///
+    /// The copy keeps the title, amount, epoch, tags and signal trigger, and its
+    /// `end_datetime` is shifted so the event keeps the same length. It is a new
+    /// event, so it gets a fresh id and creation date.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_start` - The start date and time of the copy.
+    pub fn rebase(&self, new_start: DateTime<Utc>) -> Event {
+        let length = self.end_datetime.signed_duration_since(self.start_datetime);
+        Event::new(
+            self.title.clone(),
+            self.amount.clone(),
+            self.epoch,
+            self.tags.clone(),
+            self.signal_trigger.clone(),
+            new_start,
+            new_start + length,
+        )
+    }
+
+    /// Returns the credit owed for the unused remainder of the current period
+    /// when the event is cancelled at `cancel_at`.
+    ///
+    /// The credit is the fraction of the period remaining after `cancel_at`
+    /// multiplied by `amount`, returned as a negative `Money`. Cancelling exactly
+    /// at a period boundary, before the event starts or after it ends owes
+    /// nothing.
+    pub fn proration_credit(&self, cancel_at: DateTime<Utc>) -> Money {
+        if cancel_at >= self.end_datetime {
+            return Money::new(0, 0);
+        }
+        let Some((from, to)) = self.epoch.period_containing(self.start_datetime, cancel_at) else {
+            return Money::new(0, 0);
+        };
+        if cancel_at == from {
+            return Money::new(0, 0);
+        }
+
+        let remaining = to.signed_duration_since(cancel_at).num_seconds() as i128;
+        let period = to.signed_duration_since(from).num_seconds() as i128;
+        let credit = self.amount.saturating_total_cents() as i128 * remaining / period;
+        Money::from_cents(-(credit as i64))
+    }
+
+    /// Returns what the event costs over a year, i.e. `amount` times the number
+    /// of times the epoch recurs in a year.
+    ///
+    /// Years are approximated as 365 days, 52 weeks or 12 months, and partial
+    /// cents are truncated. A `SingleDay` event is charged once.
+    pub fn annualized_cost(&self) -> Money {
+        let (per_year, every) = match self.epoch {
+            Epoch::SingleDay => (1, 1),
+            Epoch::Year(cd) => (1, cd.amount * cd.coefficient),
+            Epoch::Month(cd) | Epoch::MonthEnd(cd) => (12, cd.amount * cd.coefficient),
+            Epoch::Week(cd) => (52, cd.amount * cd.coefficient),
+            Epoch::WeeklyOn(mask, cd) => (
+                52 * mask.weekdays().len() as i64,
+                cd.amount * cd.coefficient,
+            ),
+            Epoch::Day(cd) => (crate::DAYS_IN_YEAR, cd.amount * cd.coefficient),
+        };
+        let cents =
+            self.amount.saturating_total_cents() as i128 * per_year as i128 / every.max(1) as i128;
+        Money::from_cents(cents.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
+    /// Returns the cost of the event per day, `amount` spread over the days of
+    /// one epoch period (see `Epoch::approx_days_per_period`).
+    ///
+    /// Partial cents are truncated. A `SingleDay` event costs its full amount.
+    pub fn daily_rate(&self) -> Money {
+        self.amount.clone() / self.epoch.approx_days_per_period().max(1)
+    }
+
+    /// Returns true if both events have the same content, i.e. every field
+    /// except the bookkeeping ones: `id`, `created_at` and `last_updated`.
+    pub fn content_eq(&self, other: &Event) -> bool {
+        self.title == other.title
+            && self.amount == other.amount
+            && self.epoch == other.epoch
+            && self.tags == other.tags
+            && self.signal_trigger == other.signal_trigger
+            && self.start_datetime == other.start_datetime
+            && self.end_datetime == other.end_datetime
+    }
+
+    /// Returns a hash of the event's content, consistent with `content_eq`, for
+    /// detecting changes across syncs.
+    ///
+    /// The hash is 64-bit FNV-1a, which unlike `std`'s default hasher is stable
+    /// across Rust releases and processes, so it can be stored and compared later.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv1a::default();
+        hasher.write_str(&self.title);
+        hasher.write_i64(self.amount.whole);
+        hasher.write_i64(self.amount.fractional);
+        hasher.write_str(&self.epoch.to_string());
+        match &self.tags {
+            Some(tags) => {
+                hasher.write(&[1]);
+                hasher.write_i64(tags.len() as i64);
+                tags.iter().for_each(|tag| hasher.write_str(tag));
+            }
+            None => hasher.write(&[0]),
+        }
+        let time = &self.signal_trigger.time;
+        hasher.write_i64(time.hour as i64);
+        hasher.write_i64(time.minute as i64);
+        hasher.write_i64(time.seconds as i64);
+        hasher.write_i64(self.signal_trigger.interval_seconds);
+        for datetime in [self.start_datetime, self.end_datetime] {
+            hasher.write_i64(datetime.timestamp());
+            hasher.write_i64(datetime.timestamp_subsec_nanos() as i64);
+        }
+        hasher.0
+    }
+
     /// Create a public method in the Event struct to retrieve the id field.
     /// This method can be used by external code, including tests, to access the id.
     pub fn id(&self) -> &[u8] {
         &self.id
     }
+
+    /// Returns the date and time when the event was created.
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns the date and time when the event was last updated.
+    pub fn last_updated(&self) -> DateTime<Utc> {
+        self.last_updated
+    }
+
+    /// Returns the event's id as a `GlobalId`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stored id is not 12 bytes long.
+    pub fn global_id(&self) -> GlobalId {
+        GlobalId::from_vec(self.id.clone())
+    }
+}
+
+/// Groups events by how long ago they were created, relative to `now`.
+///
+/// The creation time is read from each event's `GlobalId`. Buckets are
+/// calendar based (UTC): `"today"` is the same day as `now`, `"this_week"` the
+/// same ISO week, `"this_month"` the same month, and everything else (including
+/// ids created after `now`) lands in `"older"`. Empty buckets are omitted.
+///
+/// # Arguments
+///
+/// * `events` - The events to group.
+/// * `now` - The reference point for the buckets.
+///
+/// # Returns
+///
+/// A map from bucket name to the events in it, in input order.
+pub fn bucket_by_age(events: &[Event], now: DateTime<Utc>) -> HashMap<&'static str, Vec<&Event>> {
+    let today = now.date_naive();
+    let mut buckets: HashMap<&'static str, Vec<&Event>> = HashMap::new();
+
+    for event in events {
+        let created = event.global_id().created_at().date_naive();
+        let bucket = if created > today {
+            "older"
+        } else if created == today {
+            "today"
+        } else if created.iso_week() == today.iso_week() {
+            "this_week"
+        } else if created.year() == today.year() && created.month() == today.month() {
+            "this_month"
+        } else {
+            "older"
+        };
+        buckets.entry(bucket).or_default().push(event);
+    }
+
+    buckets
+}
+
+/// Returns the tags carried by either event. Events without tags contribute
+/// nothing.
+pub fn tag_union(a: &Event, b: &Event) -> HashSet<String> {
+    event_tags(a).union(&event_tags(b)).cloned().collect()
+}
+
+/// Returns the tags carried by both events. Events without tags share none.
+pub fn tag_intersection(a: &Event, b: &Event) -> HashSet<String> {
+    event_tags(a)
+        .intersection(&event_tags(b))
+        .cloned()
+        .collect()
+}
+
+/// Sums each event's `annualized_cost` under every tag it carries.
+///
+/// An event with several tags contributes its full cost to each of them, so the
+/// totals can add up to more than the overall cost. Events without tags are
+/// summed under `"untagged"`.
+///
+/// # Examples
+///
+/// ```
+/// use event_pulse::models::event::annual_cost_by_tag;
+///
+/// let totals = annual_cost_by_tag(&[]);
+/// assert!(totals.is_empty());
+/// ```
+pub fn annual_cost_by_tag(events: &[Event]) -> HashMap<String, Money> {
+    let mut cents: HashMap<String, i64> = HashMap::new();
+    for event in events {
+        let cost = event.annualized_cost().saturating_total_cents();
+        let mut tags = event_tags(event);
+        if tags.is_empty() {
+            tags.insert("untagged".to_string());
+        }
+        for tag in tags {
+            let total = cents.entry(tag).or_insert(0);
+            *total = total.saturating_add(cost);
+        }
+    }
+
+    cents
+        .into_iter()
+        .map(|(tag, total)| (tag, Money::from_cents(total)))
+        .collect()
+}
+
+/// Sums `EventManager::tag_counts` across `managers`, e.g. to roll up tag
+/// usage over several tenants.
+pub fn combined_tag_counts(managers: &[&EventManager]) -> HashMap<String, usize> {
+    let mut combined = HashMap::new();
+    for (tag, count) in managers.iter().flat_map(|manager| manager.tag_counts()) {
+        *combined.entry(tag).or_default() += count;
+    }
+    combined
+}
+
+/// Returns the median `amount` of `events`, or `None` for an empty slice.
+///
+/// For an even count this is the average of the two middle amounts, with a
+/// partial cent truncated toward zero.
+pub fn median_amount(events: &[Event]) -> Option<Money> {
+    let mut amounts: Vec<&Money> = events.iter().map(|event| &event.amount).collect();
+    amounts.sort();
+    let middle = amounts.len() / 2;
+    match amounts.len() {
+        0 => None,
+        len if len % 2 == 1 => Some(amounts[middle].clone()),
+        _ => Some((amounts[middle - 1].clone() + amounts[middle].clone()) / 2),
+    }
+}
+
+/// Returns the event with the lowest `amount`, or `None` for an empty slice.
+///
+/// Ties are broken in favour of the event created first.
+pub fn cheapest(events: &[Event]) -> Option<&Event> {
+    events.iter().min_by(|a, b| {
+        a.amount
+            .cmp(&b.amount)
+            .then_with(|| a.created_at.cmp(&b.created_at))
+    })
+}
+
+/// Returns the event with the highest `amount`, or `None` for an empty slice.
+///
+/// Ties are broken in favour of the event created first.
+pub fn most_expensive(events: &[Event]) -> Option<&Event> {
+    events.iter().max_by(|a, b| {
+        a.amount
+            .cmp(&b.amount)
+            .then_with(|| b.created_at.cmp(&a.created_at))
+    })
+}
+
+/// Returns a deterministic RGB color for `tag`, e.g. for UI tag chips.
+///
+/// The color is derived from a stable hash of the tag, so the same tag gets
+/// the same color across calls, processes and releases. Tags are hashed as
+/// given; normalize them first if `"Bills"` and `"bills"` should match.
+///
+/// # Examples
+///
+/// ```
+/// use event_pulse::models::event::tag_color;
+///
+/// assert_eq!(tag_color("utilities"), tag_color("utilities"));
+/// ```
+pub fn tag_color(tag: &str) -> (u8, u8, u8) {
+    let mut hasher = Fnv1a::default();
+    hasher.write(tag.as_bytes());
+    let hash = hasher.0 ^ (hasher.0 >> 32);
+    ((hash >> 16) as u8, (hash >> 8) as u8, hash as u8)
+}
+
+/// A 64-bit FNV-1a hasher, used where hashes must be stable over time.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_i64(&mut self, value: i64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    /// Writes the length first so adjacent strings can't run into each other.
+    fn write_str(&mut self, value: &str) {
+        self.write_i64(value.len() as i64);
+        self.write(value.as_bytes());
+    }
+}
+
+/// Formats a date and time as an iCalendar UTC date-time, e.g. `20240101T090000Z`.
+fn ical_datetime(datetime: DateTime<Utc>) -> String {
+    datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes an iCalendar text value.
+fn ical_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Collects an event's own `tags` field into a set.
+fn event_tags(event: &Event) -> HashSet<String> {
+    event.tags.iter().flatten().cloned().collect()
 }
 
 /// Manages events and their associated tags.
@@ -84,6 +666,8 @@ impl Event {
 /// their associated tags.
 pub struct EventManager {
     event_tags_map: HashMap<Vec<u8>, HashSet<String>>,
+    // Reverse index of `event_tags_map`, kept in sync by every mutation.
+    tag_events_map: HashMap<String, HashSet<Vec<u8>>>,
 }
 
 /// Manages events and their associated tags.
@@ -100,6 +684,7 @@ impl EventManager {
     pub fn new() -> Self {
         Self {
             event_tags_map: HashMap::new(),
+            tag_events_map: HashMap::new(),
         }
     }
 
@@ -141,6 +726,10 @@ impl EventManager {
                 .entry(event_id.clone())
                 .or_insert_with(HashSet::new)
                 .insert(tag.clone());
+            self.tag_events_map
+                .entry(tag.clone())
+                .or_default()
+                .insert(event_id.clone());
         }
         info!("Event tagged with {:?}: {:?}", event_id, tags);
     }
@@ -193,54 +782,115 @@ impl EventManager {
         tags
     }
 
+    /// Returns the IDs of the events tagged with `tag`, sorted.
+    ///
+    /// The events are looked up in the reverse tag index rather than by scanning
+    /// every event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::event::EventManager;
+    ///
+    /// let event_manager = EventManager::new();
+    /// assert!(event_manager.events_with_tag("tag1").is_empty());
+    /// ```
+    pub fn events_with_tag(&self, tag: &str) -> Vec<Vec<u8>> {
+        let mut event_ids: Vec<Vec<u8>> = self
+            .tag_events_map
+            .get(tag)
+            .map(|event_ids| event_ids.iter().cloned().collect())
+            .unwrap_or_default();
+        event_ids.sort();
+        event_ids
+    }
+
+    /// Returns the IDs of the events tagged with every one of `tags`, sorted.
+    ///
+    /// An empty `tags` slice matches every tagged event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::event::EventManager;
+    ///
+    /// let event_manager = EventManager::new();
+    /// assert!(event_manager.events_with_all_tags(&["tag1", "tag2"]).is_empty());
+    /// ```
+    pub fn events_with_all_tags(&self, tags: &[&str]) -> Vec<Vec<u8>> {
+        let mut event_ids: Vec<Vec<u8>> = self
+            .event_tags_map
+            .iter()
+            .filter(|(_, event_tags)| tags.iter().all(|tag| event_tags.contains(*tag)))
+            .map(|(event_id, _)| event_id.clone())
+            .collect();
+        event_ids.sort();
+        event_ids
+    }
+
+    /// Returns the number of events carrying each tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::event::EventManager;
+    ///
+    /// let event_manager = EventManager::new();
+    /// assert!(event_manager.tag_counts().is_empty());
+    /// ```
+    pub fn tag_counts(&self) -> HashMap<String, usize> {
+        self.tag_events_map
+            .iter()
+            .map(|(tag, event_ids)| (tag.clone(), event_ids.len()))
+            .collect()
+    }
+
     /// Deletes a tag from all associated events.
     ///
-    /// If the tag is associated with multiple events, a warning will be
-    /// logged, and the tag will not be deleted.
+    /// If the tag is associated with multiple events, a warning listing the
+    /// affected event IDs will be logged. Events left without tags are removed.
     ///
     /// # Arguments
     ///
     /// * `tag` - The tag to delete.
     ///
+    /// # Returns
+    ///
+    /// The IDs of the events the tag was removed from, sorted.
+    ///
     /// # Examples
     ///
     /// ```
     /// use event_pulse::models::event::EventManager;
     ///
     /// let mut event_manager = EventManager::new();
-    /// event_manager.delete_tag("tag1");
+    /// assert!(event_manager.delete_tag("tag1").is_empty());
     /// ```
-    pub fn delete_tag(&mut self, tag: &str) {
-        let mut more_than_one_association = false;
-        let mut event_ids_to_remove: Vec<Vec<u8>> = Vec::new();
-
-        for (event_id, tags) in self.event_tags_map.iter_mut() {
-            if tags.contains(tag) {
-                if tags.len() > 1 {
-                    more_than_one_association = true;
-                }
+    pub fn delete_tag(&mut self, tag: &str) -> Vec<Vec<u8>> {
+        // Collect the affected IDs before the maps are changed.
+        let mut affected_event_ids: Vec<Vec<u8>> = self
+            .tag_events_map
+            .remove(tag)
+            .map(|event_ids| event_ids.into_iter().collect())
+            .unwrap_or_default();
+        affected_event_ids.sort();
+
+        for event_id in &affected_event_ids {
+            if let Some(tags) = self.event_tags_map.get_mut(event_id) {
                 tags.remove(tag);
                 if tags.is_empty() {
-                    event_ids_to_remove.push(event_id.clone());
+                    self.event_tags_map.remove(event_id);
                 }
             }
         }
 
-        if more_than_one_association {
-            let affected_event_ids: Vec<_> = self
-                .event_tags_map
-                .iter()
-                .filter(|(_, tags)| tags.contains(tag))
-                .map(|(event_id, _)| event_id.clone())
-                .collect();
+        if affected_event_ids.len() > 1 {
             warn!("Tag '{}' was associated with more than one event. Removing the tag from the following event IDs: {:?}", tag, affected_event_ids);
         } else {
             info!("Tag '{}' deleted", tag);
         }
 
-        for event_id in event_ids_to_remove {
-            self.event_tags_map.remove(&event_id);
-        }
+        affected_event_ids
     }
 
     /// Removes an event ID from its associated tag map.
@@ -265,9 +915,690 @@ impl EventManager {
             if tags.is_empty() {
                 self.event_tags_map.remove(event_id);
             }
+            if let Some(event_ids) = self.tag_events_map.get_mut(tag) {
+                event_ids.remove(event_id);
+                if event_ids.is_empty() {
+                    self.tag_events_map.remove(tag);
+                }
+            }
             info!("Event {:?} removed from tag '{}'", event_id, tag);
         } else {
             warn!("Event {:?} not found", event_id);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn monthly_event(amount: Money, start: DateTime<Utc>, end: DateTime<Utc>) -> Event {
+        Event::new(
+            "Streaming".to_string(),
+            amount,
+            Epoch::Month(crate::models::CalendarData::new(1, 1)),
+            None,
+            SignalTrigger::from_str("M09:00:00::I86400").expect("valid signal trigger"),
+            start,
+            end,
+        )
+    }
+
+    fn created_event(created: DateTime<Utc>) -> Event {
+        let mut event = monthly_event(Money::new(1, 0), created, created);
+        let mut id = b"EVNT".to_vec();
+        id.extend_from_slice(&((created.timestamp_millis() as u64) << 16).to_be_bytes());
+        event.id = id;
+        event
+    }
+
+    #[test]
+    fn test_remaining_value() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 4, 9, 0, 0).unwrap();
+        let event = Event::new(
+            "Lessons".to_string(),
+            Money::new(10, 0),
+            Epoch::Week(crate::models::CalendarData::new(1, 1)),
+            None,
+            SignalTrigger::from_str("M09:00:00::I86400").expect("valid signal trigger"),
+            start,
+            start + Duration::try_weeks(8).expect("valid duration"),
+        );
+        let two_weeks_in = start + Duration::try_weeks(2).expect("valid duration");
+
+        assert_eq!(event.elapsed_periods(two_weeks_in), 2);
+        assert_eq!(
+            event.remaining_value(two_weeks_in, Money::new(40, 0)),
+            Money::new(20, 0)
+        );
+        assert_eq!(
+            event.remaining_value(
+                start - Duration::try_days(1).expect("valid duration"),
+                Money::new(40, 0)
+            ),
+            Money::new(40, 0)
+        );
+        // Overdrawn past four periods; counting stops at the end of the event.
+        let later = start + Duration::try_weeks(20).expect("valid duration");
+        assert_eq!(event.elapsed_periods(later), 8);
+        assert_eq!(
+            event.remaining_value(later, Money::new(40, 0)),
+            Money::new(-40, 0)
+        );
+    }
+
+    #[test]
+    fn test_bucket_by_age() {
+        // Thursday
+        let now = Utc.with_ymd_and_hms(2024, 5, 16, 12, 0, 0).unwrap();
+        let events = vec![
+            created_event(Utc.with_ymd_and_hms(2024, 5, 16, 8, 0, 0).unwrap()),
+            created_event(Utc.with_ymd_and_hms(2024, 5, 13, 8, 0, 0).unwrap()),
+            created_event(Utc.with_ymd_and_hms(2024, 5, 2, 8, 0, 0).unwrap()),
+            created_event(Utc.with_ymd_and_hms(2023, 5, 16, 8, 0, 0).unwrap()),
+        ];
+
+        let buckets = bucket_by_age(&events, now);
+        let ids = |name: &str| -> Vec<&[u8]> { buckets[name].iter().map(|e| e.id()).collect() };
+        assert_eq!(ids("today"), vec![events[0].id()]);
+        assert_eq!(ids("this_week"), vec![events[1].id()]);
+        assert_eq!(ids("this_month"), vec![events[2].id()]);
+        assert_eq!(ids("older"), vec![events[3].id()]);
+        assert_eq!(
+            events[1].global_id().created_at(),
+            Utc.with_ymd_and_hms(2024, 5, 13, 8, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_days_until_start() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 6, 12, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let event = monthly_event(Money::new(5, 0), start, end);
+
+        assert_eq!(event.days_until_start(now), 5);
+        assert_eq!(
+            event.days_until_start(Utc.with_ymd_and_hms(2024, 1, 9, 12, 0, 0).unwrap()),
+            -3
+        );
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let event = monthly_event(Money::new(5, 0), start, end);
+
+        assert!(!event.is_expired(end - Duration::try_seconds(1).expect("1 second")));
+        assert!(event.is_expired(end));
+    }
+
+    #[test]
+    fn test_lifetime_before_during_and_after() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap();
+        let event = monthly_event(Money::new(5, 0), start, end);
+        let day = Duration::try_days(1).expect("1 day");
+
+        let before = start - day;
+        assert!(!event.is_active(before) && !event.is_expired(before));
+        assert_eq!(event.time_remaining(before), Some(end - before));
+        assert_eq!(event.progress(before), 0.0);
+
+        let mid = start + day * 4;
+        assert!(event.is_active(mid));
+        assert_eq!(event.time_remaining(mid), Some(day * 6));
+        assert!((event.progress(mid) - 0.4).abs() < 1e-9);
+
+        assert!(!event.is_active(end) && event.is_expired(end));
+        assert_eq!(event.time_remaining(end), None);
+        assert_eq!(event.progress(end), 1.0);
+
+        let after = end + day;
+        assert_eq!(event.time_remaining(after), None);
+        assert_eq!(event.progress(after), 1.0);
+    }
+
+    #[test]
+    fn test_lifetime_of_instant_event() {
+        let at = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let event = monthly_event(Money::new(5, 0), at, at);
+        let second = Duration::try_seconds(1).expect("1 second");
+
+        assert_eq!(event.progress(at - second), 0.0);
+        assert!(!event.is_active(at - second));
+        assert_eq!(event.time_remaining(at - second), Some(second));
+        assert!(!event.is_active(at));
+        assert_eq!(event.time_remaining(at), None);
+        assert_eq!(event.progress(at), 1.0);
+        assert_eq!(event.progress(at + second), 1.0);
+    }
+
+    #[test]
+    fn test_timestamp_range() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let event = monthly_event(Money::new(5, 0), start, end);
+
+        let (from, to) = event.timestamp_range();
+        assert_eq!(from, 1_704_067_200);
+        assert_eq!(to - from, end.signed_duration_since(start).num_seconds());
+    }
+
+    #[test]
+    fn test_annual_cost_by_tag() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut music = monthly_event(Money::new(10, 99), start, end);
+        music.tags = Some(vec!["streaming".to_string(), "music".to_string()]);
+        let mut video = monthly_event(Money::new(15, 0), start, end);
+        video.tags = Some(vec!["streaming".to_string()]);
+        let untagged = monthly_event(Money::new(1, 0), start, end);
+
+        let totals = annual_cost_by_tag(&[music, video, untagged]);
+        assert_eq!(totals.len(), 3);
+        assert_eq!(totals["streaming"], Money::new(311, 88));
+        assert_eq!(totals["music"], Money::new(131, 88));
+        assert_eq!(totals["untagged"], Money::new(12, 0));
+    }
+
+    #[test]
+    fn test_reconcile_charges() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let event = monthly_event(Money::new(20, 0), start, end);
+
+        // Three months in: charged Jan 1, Feb 1 and Mar 1.
+        let now = Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap();
+        assert_eq!(event.expected_occurrences(now), 3);
+        assert_eq!(event.reconcile_charges(now, 2), 1);
+        assert_eq!(event.reconcile_charges(now, 4), -1);
+        // A charge due exactly at `now` counts as expected.
+        let now = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        assert_eq!(event.reconcile_charges(now, 4), 0);
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut event = monthly_event(Money::new(10, 0), start, end);
+        event.tags = Some(vec!["streaming".to_string()]);
+        let mut copy = event.clone();
+        copy.id = vec![9];
+        copy.created_at = end;
+        copy.last_updated = end;
+
+        assert!(event.content_eq(&copy));
+        assert_eq!(event.content_hash(), copy.content_hash());
+
+        copy.title = "Streaming (family)".to_string();
+        assert!(!event.content_eq(&copy));
+        assert_ne!(event.content_hash(), copy.content_hash());
+    }
+
+    #[test]
+    fn test_daily_rate() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut event = monthly_event(Money::new(30, 0), start, end);
+        assert_eq!(event.daily_rate(), Money::new(1, 0));
+
+        event.epoch = Epoch::SingleDay;
+        assert_eq!(event.daily_rate(), Money::new(30, 0));
+    }
+
+    #[test]
+    fn test_cheapest_and_most_expensive() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut events = vec![
+            monthly_event(Money::new(9, 99), start, end),
+            monthly_event(Money::new(4, 50), start, end),
+            monthly_event(Money::new(15, 0), start, end),
+        ];
+        for (index, event) in events.iter_mut().enumerate() {
+            event.id = vec![index as u8];
+        }
+
+        assert_eq!(cheapest(&events).map(Event::id), Some(&[1][..]));
+        assert_eq!(most_expensive(&events).map(Event::id), Some(&[2][..]));
+        assert_eq!(cheapest(&[]), None);
+        assert_eq!(most_expensive(&[]), None);
+    }
+
+    #[test]
+    fn test_median_amount_odd_count() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let events: Vec<_> = [Money::new(30, 0), Money::new(5, 99), Money::new(12, 50)]
+            .into_iter()
+            .map(|amount| monthly_event(amount, start, end))
+            .collect();
+
+        assert_eq!(median_amount(&events), Some(Money::new(12, 50)));
+        assert_eq!(median_amount(&events[..1]), Some(Money::new(30, 0)));
+        assert_eq!(median_amount(&[]), None);
+    }
+
+    #[test]
+    fn test_median_amount_even_count() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let events: Vec<_> = [
+            Money::new(100, 0),
+            Money::new(10, 60),
+            Money::new(1, 0),
+            Money::new(9, 99),
+        ]
+        .into_iter()
+        .map(|amount| monthly_event(amount, start, end))
+        .collect();
+
+        // (9.99 + 10.60) / 2 = 10.295, truncated.
+        assert_eq!(median_amount(&events), Some(Money::new(10, 29)));
+    }
+
+    #[test]
+    fn test_cheapest_ties_prefer_earliest_created() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut older = monthly_event(Money::new(5, 0), start, end);
+        older.id = vec![1];
+        older.created_at = start;
+        let mut newer = monthly_event(Money::new(5, 0), start, end);
+        newer.id = vec![2];
+        newer.created_at = end;
+        let events = [newer, older];
+
+        assert_eq!(cheapest(&events).map(Event::id), Some(&[1][..]));
+        assert_eq!(most_expensive(&events).map(Event::id), Some(&[1][..]));
+    }
+
+    #[test]
+    fn test_to_vevent() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 7, 1, 9, 0, 0).unwrap();
+        let mut event = monthly_event(Money::new(15, 0), start, end);
+        event.title = "Rent; flat 2, top".to_string();
+        event.tags = Some(vec!["home".to_string(), "bills".to_string()]);
+
+        let vevent = event.to_vevent();
+        let lines: Vec<&str> = vevent.split("\r\n").collect();
+        assert_eq!(lines.first(), Some(&"BEGIN:VEVENT"));
+        assert_eq!(lines.last(), Some(&"END:VEVENT"));
+        assert!(lines.contains(&format!("UID:{}@event-pulse", event.global_id()).as_str()));
+        assert!(lines.contains(&"DTSTART:20240101T090000Z"));
+        assert!(lines.contains(&"SUMMARY:Rent\\; flat 2\\, top"));
+        assert!(lines.contains(&"RRULE:FREQ=MONTHLY;INTERVAL=1;UNTIL=20240701T085959Z"));
+        assert!(lines.contains(&"CATEGORIES:home,bills"));
+    }
+
+    #[test]
+    fn test_set_end_extends_event() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap();
+        let mut event = monthly_event(Money::new(5, 0), start, end);
+        let after = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        assert_eq!(event.next_occurrence(after), None);
+
+        let new_end = Utc.with_ymd_and_hms(2024, 6, 15, 0, 0, 0).unwrap();
+        assert_eq!(event.set_end(new_end), Ok(()));
+        assert_eq!(event.end_datetime, new_end);
+        assert!(event.last_updated() >= event.created_at());
+        assert_eq!(
+            event.next_occurrence(after),
+            Some(Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_period_coverage() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 4, 9, 0, 0).unwrap();
+        let weekly = |days, amount| {
+            Event::new(
+                "Rental".to_string(),
+                Money::new(10, 0),
+                Epoch::Week(crate::models::CalendarData::new(amount, 1)),
+                None,
+                SignalTrigger::from_str("M09:00:00::I86400").expect("valid signal trigger"),
+                start,
+                start + Duration::try_days(days).expect("valid duration"),
+            )
+        };
+
+        assert!((weekly(3, 1).period_coverage() - 3.0 / 7.0).abs() < 1e-9);
+        assert!((weekly(5, 1).period_coverage() - 0.714).abs() < 1e-3);
+        assert_eq!(weekly(30, 1).period_coverage(), 1.0);
+        // A zero-length epoch must not divide by zero.
+        assert_eq!(weekly(3, 0).period_coverage(), 0.0);
+    }
+
+    #[test]
+    fn test_sort_key_breaks_start_ties_by_title() {
+        let early = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let late = Utc.with_ymd_and_hms(2024, 2, 1, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let titled = |title: &str, start| {
+            let mut event = monthly_event(Money::new(5, 0), start, end);
+            event.title = title.to_string();
+            event
+        };
+        let mut events = [
+            titled("Rent", late),
+            titled("Streaming", early),
+            titled("Gym", early),
+            titled("Insurance", late),
+        ];
+
+        events.sort_by_key(Event::sort_key);
+        let titles: Vec<&str> = events.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, vec!["Gym", "Streaming", "Insurance", "Rent"]);
+    }
+
+    #[test]
+    fn test_truncate_start_to_day() {
+        use chrono::Timelike;
+
+        let start = Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap()
+            + Duration::try_milliseconds(250).expect("valid duration");
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut event = monthly_event(Money::new(5, 0), start, end);
+        event.truncate_start_to_day();
+
+        assert_eq!(event.start_datetime.date_naive(), start.date_naive());
+        assert_eq!(
+            (
+                event.start_datetime.hour(),
+                event.start_datetime.minute(),
+                event.start_datetime.second(),
+                event.start_datetime.nanosecond()
+            ),
+            (0, 0, 0, 0)
+        );
+        assert!(event.last_updated() >= event.created_at());
+    }
+
+    #[test]
+    fn test_set_end_before_start_is_rejected() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap();
+        let mut event = monthly_event(Money::new(5, 0), start, end);
+
+        let result = event.set_end(Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap());
+        assert!(matches!(result, Err(AppError::InvalidInputString(_))));
+        assert!(event.set_end(start).is_err());
+        assert_eq!(event.end_datetime, end);
+    }
+
+    #[test]
+    fn test_rebase_preserves_duration() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 7, 10, 9, 0, 0).unwrap();
+        let mut event = monthly_event(Money::new(15, 0), start, end);
+        event.tags = Some(vec!["media".to_string()]);
+        event.id = vec![1];
+
+        let new_start = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let rebased = event.rebase(new_start);
+
+        assert_ne!(rebased.id(), event.id());
+        assert_eq!(rebased.start_datetime, new_start);
+        assert_eq!(
+            rebased.end_datetime - rebased.start_datetime,
+            event.end_datetime - event.start_datetime
+        );
+        assert_eq!(rebased.epoch, event.epoch);
+        assert_eq!(rebased.amount, event.amount);
+        assert_eq!(rebased.tags, event.tags);
+    }
+
+    #[test]
+    fn test_occurrences_weekly_over_30_days() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 9, 0, 0).unwrap();
+        let end = start + Duration::try_days(30).unwrap();
+        let mut event = monthly_event(Money::new(10, 0), start, end);
+        event.epoch = Epoch::Week(crate::models::CalendarData::new(1, 1));
+
+        let occurrences = event.occurrences();
+        // Apr 1, 8, 15, 22 and 29; May 1 is the (exclusive) end.
+        assert_eq!(occurrences.len(), 5);
+        assert_eq!(occurrences.first(), Some(&start));
+        assert!(occurrences.iter().all(|occurrence| *occurrence < end));
+    }
+
+    #[test]
+    fn test_events_with_all_tags() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let mut full = monthly_event(Money::new(10, 0), start, end);
+        full.id = vec![1];
+        let mut partial = monthly_event(Money::new(10, 0), start, end);
+        partial.id = vec![2];
+
+        let mut event_manager = EventManager::new();
+        let tags = |tags: &[&str]| tags.iter().map(|tag| tag.to_string()).collect();
+        event_manager.tag_event(full, tags(&["streaming", "music", "family"]));
+        event_manager.tag_event(partial, tags(&["streaming", "video"]));
+
+        assert_eq!(
+            event_manager.events_with_all_tags(&["streaming", "music"]),
+            vec![vec![1]]
+        );
+        assert_eq!(
+            event_manager.events_with_tag("streaming"),
+            vec![vec![1], vec![2]]
+        );
+        assert!(event_manager
+            .events_with_all_tags(&["music", "video"])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_combined_tag_counts() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let tags = |tags: &[&str]| tags.iter().map(|tag| tag.to_string()).collect();
+        let event = |id: u8| {
+            let mut event = monthly_event(Money::new(10, 0), start, end);
+            event.id = vec![id];
+            event
+        };
+
+        let mut first = EventManager::new();
+        first.tag_event(event(1), tags(&["streaming", "music"]));
+        first.tag_event(event(2), tags(&["streaming"]));
+        let mut second = EventManager::new();
+        second.tag_event(event(1), tags(&["streaming", "video"]));
+
+        assert_eq!(first.tag_counts()["streaming"], 2);
+        let combined = combined_tag_counts(&[&first, &second]);
+        assert_eq!(combined.len(), 3);
+        assert_eq!(combined["streaming"], 3);
+        assert_eq!(combined["music"], 1);
+        assert_eq!(combined["video"], 1);
+        assert!(combined_tag_counts(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_budget_exhausted_at() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let event = Event::new(
+            "Meal kit".to_string(),
+            Money::new(10, 0),
+            Epoch::Week(crate::models::CalendarData::new(1, 1)),
+            None,
+            SignalTrigger::from_str("M09:00:00::I604800").expect("valid signal trigger"),
+            start,
+            Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap(),
+        );
+
+        assert_eq!(
+            event.budget_exhausted_at(Money::new(35, 0)),
+            Some(Utc.with_ymd_and_hms(2024, 1, 22, 0, 0, 0).unwrap())
+        );
+        assert_eq!(event.budget_exhausted_at(Money::new(10_000, 0)), None);
+    }
+
+    #[test]
+    fn test_align_start_to_epoch_first_of_month() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 14, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 9, 14, 10, 0, 0).unwrap();
+        let mut event = monthly_event(Money::new(30, 0), start, end);
+
+        let anchor = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        event.align_start_to_epoch(anchor);
+        assert_eq!(
+            event.start_datetime,
+            Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()
+        );
+        // The end moves by the same 17 days and 14 hours.
+        assert_eq!(
+            event.end_datetime,
+            Utc.with_ymd_and_hms(2024, 10, 2, 0, 0, 0).unwrap()
+        );
+
+        // Already on a boundary: nothing moves.
+        event.align_start_to_epoch(anchor);
+        assert_eq!(
+            event.start_datetime,
+            Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    fn tagged_event(tags: Option<Vec<&str>>) -> Event {
+        let mut event = monthly_event(Money::new(10, 0), Utc::now(), Utc::now());
+        event.tags = tags.map(|tags| tags.into_iter().map(String::from).collect());
+        event
+    }
+
+    #[test]
+    fn test_tag_union_and_intersection_overlapping() {
+        let a = tagged_event(Some(vec!["streaming", "music"]));
+        let b = tagged_event(Some(vec!["streaming", "video"]));
+
+        let union = tag_union(&a, &b);
+        assert_eq!(union.len(), 3);
+        assert!(union.contains("music") && union.contains("video"));
+        assert_eq!(
+            tag_intersection(&a, &b),
+            HashSet::from(["streaming".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_tag_union_and_intersection_disjoint() {
+        let a = tagged_event(Some(vec!["insurance"]));
+        let b = tagged_event(Some(vec!["streaming"]));
+        let untagged = tagged_event(None);
+
+        assert_eq!(tag_union(&a, &b).len(), 2);
+        assert!(tag_intersection(&a, &b).is_empty());
+        assert_eq!(
+            tag_union(&a, &untagged),
+            HashSet::from(["insurance".to_string()])
+        );
+        assert!(tag_intersection(&a, &untagged).is_empty());
+    }
+
+    #[test]
+    fn test_tag_color_is_stable_and_spread() {
+        assert_eq!(tag_color("streaming"), tag_color("streaming"));
+        assert_eq!(tag_color(""), tag_color(""));
+
+        let tags = [
+            "streaming",
+            "music",
+            "video",
+            "insurance",
+            "utilities",
+            "rent",
+            "gym",
+            "bills",
+        ];
+        let colors: HashSet<_> = tags.iter().map(|tag| tag_color(tag)).collect();
+        assert_eq!(colors.len(), tags.len());
+        assert_ne!(tag_color("bills"), tag_color("Bills"));
+    }
+
+    #[test]
+    fn test_delete_tag_returns_sorted_affected_ids() {
+        let mut manager = EventManager::new();
+        let mut first = tagged_event(None);
+        first.id = vec![2];
+        let mut second = tagged_event(None);
+        second.id = vec![1];
+        let mut untouched = tagged_event(None);
+        untouched.id = vec![3];
+
+        manager.tag_event(first, vec!["streaming".to_string(), "music".to_string()]);
+        manager.tag_event(second, vec!["streaming".to_string()]);
+        manager.tag_event(untouched, vec!["insurance".to_string()]);
+
+        assert_eq!(manager.delete_tag("streaming"), vec![vec![1], vec![2]]);
+        assert_eq!(
+            manager.get_tags_for_event(&[2]),
+            Some(&HashSet::from(["music".to_string()]))
+        );
+        assert_eq!(manager.get_tags_for_event(&[1]), None);
+        assert!(manager.delete_tag("streaming").is_empty());
+        assert!(manager.events_with_tag("streaming").is_empty());
+        assert_eq!(manager.events_with_tag("music"), vec![vec![2]]);
+    }
+
+    #[test]
+    fn test_events_with_tag_across_events() {
+        let mut manager = EventManager::new();
+        for id in [3u8, 1, 2] {
+            let mut event = tagged_event(None);
+            event.id = vec![id];
+            let mut tags = vec!["streaming".to_string()];
+            if id != 2 {
+                tags.push("music".to_string());
+            }
+            manager.tag_event(event, tags);
+        }
+
+        assert_eq!(
+            manager.events_with_tag("streaming"),
+            vec![vec![1], vec![2], vec![3]]
+        );
+        assert_eq!(manager.events_with_tag("music"), vec![vec![1], vec![3]]);
+        assert_eq!(manager.tag_counts().get("music"), Some(&2));
+
+        manager.remove_event_from_tag(&[1], "music");
+        assert_eq!(manager.events_with_tag("music"), vec![vec![3]]);
+        manager.remove_event_from_tag(&[3], "music");
+        assert!(manager.events_with_tag("music").is_empty());
+        assert_eq!(manager.tag_counts().get("music"), None);
+        assert_eq!(manager.events_with_all_tags(&["streaming"]).len(), 3);
+    }
+
+    #[test]
+    fn test_proration_credit_mid_month() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let event = monthly_event(Money::new(30, 0), start, end);
+
+        // 15 of April's 30 days remain unused.
+        let cancel_at = Utc.with_ymd_and_hms(2024, 4, 16, 0, 0, 0).unwrap();
+        assert_eq!(event.proration_credit(cancel_at), Money::new(-15, 0));
+
+        // 20 of May's 31 days remain unused: 30.00 * 20 / 31 = 19.35
+        let cancel_at = Utc.with_ymd_and_hms(2024, 5, 12, 0, 0, 0).unwrap();
+        assert_eq!(event.proration_credit(cancel_at), Money::new(-19, -35));
+    }
+
+    #[test]
+    fn test_proration_credit_at_period_boundary() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let event = monthly_event(Money::new(30, 0), start, end);
+
+        let cancel_at = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+        assert_eq!(event.proration_credit(cancel_at), Money::new(0, 0));
+        assert_eq!(event.proration_credit(end), Money::new(0, 0));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod calendar;
 pub mod decimal;
 pub mod epoch;
 pub mod event;
@@ -6,6 +7,6 @@ pub mod signal;
 pub mod time;
 pub mod uid;
 
-pub use epoch::{CalendarData, Epoch};
+pub use epoch::{BoundedEpoch, CalendarData, Epoch, WeeklyMask};
 pub use signal::SignalTrigger;
 pub use time::MilitaryTime;
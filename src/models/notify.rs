@@ -1,14 +1,147 @@
-use crate::models::{event::Event, signal::SignalTrigger};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use crate::error::{AppError, NotifyError};
+use crate::models::{epoch::Epoch, event::Event, signal::SignalTrigger};
 use chrono::{DateTime, Duration, Utc};
-use structsy::derive::{Persistent, PersistentEmbedded};
+use structsy::derive::{queries, Persistent, PersistentEmbedded};
 
 /// Represents a recipient who can receive notifications.
-#[derive(Debug, Clone, PartialEq, PersistentEmbedded)]
+///
+/// Both `Display` and `Debug` redact the recipient; use `recipient_id()` for
+/// the raw value.
+#[derive(Clone, PartialEq, PersistentEmbedded)]
 pub struct Recipient {
     /// Represents a recepient handle or email address, associated with `TransportMethod`
     recipient_id: String,
 }
 
+impl Recipient {
+    /// Creates a new `Recipient` from a handle or email address.
+    pub fn new(recipient_id: impl Into<String>) -> Self {
+        Self {
+            recipient_id: recipient_id.into(),
+        }
+    }
+
+    /// Returns the raw, unredacted recipient handle or email address.
+    pub fn recipient_id(&self) -> &str {
+        &self.recipient_id
+    }
+
+    /// Returns the recipient with its personal details masked, suitable for logs.
+    ///
+    /// Emails keep the first character of the local part and the domain
+    /// (`j***@example.com`); other handles, such as phone numbers, keep their
+    /// first two and last two characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::notify::Recipient;
+    ///
+    /// assert_eq!(Recipient::new("jane@example.com").redacted(), "j***@example.com");
+    /// assert_eq!(Recipient::new("+15551234567").redacted(), "+1********67");
+    /// ```
+    pub fn redacted(&self) -> String {
+        match self.recipient_id.split_once('@') {
+            Some((local, domain)) => {
+                let first: String = local.chars().take(1).collect();
+                format!("{}***@{}", first, domain)
+            }
+            None => {
+                let chars: Vec<char> = self.recipient_id.chars().collect();
+                if chars.len() <= 4 {
+                    return "*".repeat(chars.len());
+                }
+                chars
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        if i < 2 || i >= chars.len() - 2 {
+                            *c
+                        } else {
+                            '*'
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Checks that the recipient can be delivered to through `method`.
+    ///
+    /// Emails need a local part and a dotted domain, SMS recipients need 7 to 15
+    /// digits (an optional leading `+`, spaces and dashes are allowed), Slack
+    /// handles are lowercase letters, digits, `.`, `_` and `-` with an optional
+    /// leading `@` or `#`, Discord usernames are 2 to 32 lowercase letters,
+    /// digits, `.` and `_`, Telegram handles are 5 to 32 letters, digits and `_`
+    /// with an optional leading `@`, and push tokens must be non-empty without
+    /// whitespace.
+    ///
+    /// # Returns
+    ///
+    /// `NotifyError::InvalidRecipient` naming the redacted recipient and the
+    /// channel if the recipient is not deliverable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_pulse::models::notify::{Recipient, TransportMethod};
+    ///
+    /// let email = TransportMethod::Email(Recipient::new("ops@example.com"));
+    /// assert!(Recipient::new("jane@example.com").validate_for(&email).is_ok());
+    /// assert!(Recipient::new("not-an-email").validate_for(&email).is_err());
+    /// ```
+    pub fn validate_for(&self, method: &TransportMethod) -> Result<(), NotifyError> {
+        let value = self.recipient_id.as_str();
+        let valid = match method {
+            TransportMethod::Email(_) => is_email(value),
+            TransportMethod::Sms(_) => is_phone_number(value),
+            TransportMethod::PushNotification(_) => {
+                !value.is_empty() && !value.chars().any(char::is_whitespace)
+            }
+            TransportMethod::Slack(_) => is_handle(
+                value.strip_prefix(['@', '#']).unwrap_or(value),
+                1..=80,
+                |c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-'),
+            ),
+            TransportMethod::Discord(_) => is_handle(value, 2..=32, |c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_')
+            }),
+            TransportMethod::Telegram(_) => {
+                is_handle(value.strip_prefix('@').unwrap_or(value), 5..=32, |c| {
+                    c.is_ascii_alphanumeric() || c == '_'
+                })
+            }
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(NotifyError::InvalidRecipient {
+                recipient: self.redacted(),
+                channel: method.channel_name(),
+            })
+        }
+    }
+}
+
+impl std::fmt::Debug for Recipient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Recipient")
+            .field("recipient_id", &self.redacted())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Recipient {
+    /// Formats the recipient redacted; use `recipient_id()` for the raw value.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.redacted())
+    }
+}
+
 /// Represents the transport method of a notification.
 #[derive(Debug, Clone, PartialEq, PersistentEmbedded)]
 pub enum TransportMethod {
@@ -26,13 +159,189 @@ pub enum TransportMethod {
     Telegram(Recipient),
 }
 
+impl TransportMethod {
+    /// Returns the name of the channel the transport method delivers through.
+    pub fn channel_name(&self) -> &'static str {
+        match self {
+            Self::Email(_) => "email",
+            Self::Sms(_) => "sms",
+            Self::PushNotification(_) => "push",
+            Self::Slack(_) => "slack",
+            Self::Discord(_) => "discord",
+            Self::Telegram(_) => "telegram",
+        }
+    }
+
+    /// Checks that `recipient` can be delivered to through this transport
+    /// method, following the rules of `Recipient::validate_for`.
+    ///
+    /// # Returns
+    ///
+    /// `AppError::NotificationError` naming the redacted recipient and the
+    /// channel if the recipient is not deliverable.
+    pub fn validate_recipient(&self, recipient: &Recipient) -> Result<(), AppError> {
+        Ok(recipient.validate_for(self)?)
+    }
+}
+
+/// Returns true if `value` looks like `local@domain.tld`.
+fn is_email(value: &str) -> bool {
+    match value.split_once('@') {
+        Some((local, domain)) => {
+            !value.chars().any(char::is_whitespace)
+                && !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        }
+        None => false,
+    }
+}
+
+/// Returns true if `value` is a phone number of 7 to 15 digits, optionally
+/// prefixed with `+` and separated by spaces or dashes.
+fn is_phone_number(value: &str) -> bool {
+    let digits = value.strip_prefix('+').unwrap_or(value);
+    let mut count = 0;
+    for c in digits.chars() {
+        match c {
+            '0'..='9' => count += 1,
+            ' ' | '-' => {}
+            _ => return false,
+        }
+    }
+    (7..=15).contains(&count)
+}
+
+/// Returns true if `value` has a length within `len` and only characters
+/// accepted by `allowed`.
+fn is_handle(
+    value: &str,
+    len: std::ops::RangeInclusive<usize>,
+    allowed: impl Fn(char) -> bool,
+) -> bool {
+    len.contains(&value.chars().count()) && value.chars().all(allowed)
+}
+
+/// Delivers notification messages through a single channel, asynchronously.
+#[async_trait::async_trait]
+pub trait NotificationSender: Send + Sync {
+    /// Sends `message` to `recipient`.
+    async fn send(&self, recipient: &Recipient, message: &str) -> Result<(), NotifyError>;
+}
+
+/// Shares a sender, e.g. to keep a handle on a `LogTransport` after
+/// registering it.
+#[async_trait::async_trait]
+impl<T: NotificationSender + ?Sized> NotificationSender for Arc<T> {
+    async fn send(&self, recipient: &Recipient, message: &str) -> Result<(), NotifyError> {
+        self.as_ref().send(recipient, message).await
+    }
+}
+
+/// Maps channel names to the `NotificationSender` delivering through them.
+///
+/// # Examples
+///
+/// ```
+/// use event_pulse::models::notify::{LogTransport, Recipient, TransportMethod, TransportRegistry};
+///
+/// let mut registry = TransportRegistry::new();
+/// registry.register("email", Box::new(LogTransport::new()));
+/// let method = TransportMethod::Email(Recipient::new("jane@example.com"));
+/// assert!(registry.sender_for(&method).is_some());
+/// ```
+#[derive(Default)]
+pub struct TransportRegistry {
+    senders: HashMap<String, Box<dyn NotificationSender>>,
+}
+
+impl TransportRegistry {
+    /// Creates an empty `TransportRegistry`.
+    pub fn new() -> Self {
+        Self {
+            senders: HashMap::new(),
+        }
+    }
+
+    /// Registers `sender` for the channel `method_name`, replacing any sender
+    /// previously registered for it.
+    pub fn register(&mut self, method_name: &str, sender: Box<dyn NotificationSender>) {
+        self.senders.insert(method_name.to_string(), sender);
+    }
+
+    /// Returns the sender registered for the channel of `method`.
+    pub fn sender_for(&self, method: &TransportMethod) -> Option<&dyn NotificationSender> {
+        self.senders
+            .get(method.channel_name())
+            .map(|sender| sender.as_ref())
+    }
+}
+
+/// Sends `message` to `recipient` through the sender registered for the
+/// channel of `method`.
+///
+/// # Returns
+///
+/// The sender's result, or `NotifyError::NoTransport` if no sender is
+/// registered for the channel.
+pub async fn dispatch(
+    registry: &TransportRegistry,
+    method: &TransportMethod,
+    recipient: &Recipient,
+    message: &str,
+) -> Result<(), NotifyError> {
+    let sender = registry
+        .sender_for(method)
+        .ok_or_else(|| NotifyError::NoTransport(method.channel_name().to_string()))?;
+    sender.send(recipient, message).await
+}
+
+/// A `NotificationSender` that logs messages and keeps them in memory instead of
+/// delivering them, for tests and dry runs.
+#[derive(Debug, Default)]
+pub struct LogTransport {
+    messages: Mutex<Vec<(String, String)>>,
+}
+
+impl LogTransport {
+    /// Creates a `LogTransport` with no recorded messages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the `(recipient_id, message)` pairs sent so far, oldest first.
+    pub fn messages(&self) -> Vec<(String, String)> {
+        self.messages
+            .lock()
+            .expect("LogTransport lock poisoned")
+            .clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationSender for LogTransport {
+    async fn send(&self, recipient: &Recipient, message: &str) -> Result<(), NotifyError> {
+        log::info!("Notification to {}: {}", recipient, message);
+        self.messages
+            .lock()
+            .expect("LogTransport lock poisoned")
+            .push((recipient.recipient_id().to_string(), message.to_string()));
+        Ok(())
+    }
+}
+
 /// Represents the calendar date frequency of notifications.
 #[derive(Debug, Clone, PartialEq, PersistentEmbedded)]
 pub enum SendFrequency {
     /// Sends notifications immediately when triggered.
     OnTrigger,
-    /// Sends notifications one day prior to the event trigger.
+    /// Sends notifications one day prior to the event trigger, the same as a
+    /// one-day `LeadTime`.
     DayPrior,
+    /// Sends notifications the given number of seconds prior to the event trigger.
+    LeadTime(i64),
     /// Sends notifications daily.
     Daily,
     /// Sends notifications weekly.
@@ -43,6 +352,66 @@ pub enum SendFrequency {
     Monthly,
     /// Sends notifications quarterly.
     Quarterly,
+    /// Sends notifications once per period of the given epoch, e.g. following
+    /// the epoch of the scheduled event (see `EventNotify::resync_to_event`).
+    Custom(Epoch),
+}
+
+impl SendFrequency {
+    /// Returns the fixed interval between sends, or `None` for frequencies
+    /// that are relative to the event trigger (`OnTrigger`, `DayPrior` and
+    /// `LeadTime`).
+    ///
+    /// A month is 30 days and a quarter 90 days, as in the rest of the crate.
+    /// A `Custom` epoch whose period is out of range has no interval either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use event_pulse::models::notify::SendFrequency;
+    ///
+    /// assert_eq!(SendFrequency::Weekly.to_duration(), Duration::try_weeks(1));
+    /// assert_eq!(SendFrequency::DayPrior.to_duration(), None);
+    /// ```
+    pub fn to_duration(&self) -> Option<Duration> {
+        match self {
+            SendFrequency::OnTrigger | SendFrequency::DayPrior | SendFrequency::LeadTime(_) => None,
+            SendFrequency::Daily => Duration::try_seconds(crate::SECS_IN_DAY),
+            SendFrequency::Weekly => Duration::try_seconds(crate::SECS_IN_WEEK),
+            SendFrequency::BiWeekly => Duration::try_seconds(crate::SECS_IN_BI_WEEKLY),
+            SendFrequency::Monthly => Duration::try_seconds(crate::SECS_IN_MONTH),
+            SendFrequency::Quarterly => Duration::try_seconds(crate::SECS_IN_QUARTER),
+            SendFrequency::Custom(epoch) => epoch.to_duration_checked().ok(),
+        }
+    }
+
+    /// Returns the interval of the send period starting at `start`, like
+    /// `to_duration` but with a `Custom` month or year epoch measured on the
+    /// calendar (see `Epoch::to_duration_from`), e.g. 31 days for a monthly
+    /// epoch starting in January.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use event_pulse::models::notify::SendFrequency;
+    /// use event_pulse::models::{CalendarData, Epoch};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let monthly = SendFrequency::Custom(Epoch::Month(CalendarData::new(1, 1)));
+    /// assert_eq!(monthly.to_duration_from(start), Duration::try_days(31));
+    /// assert_eq!(SendFrequency::Monthly.to_duration_from(start), Duration::try_days(30));
+    /// ```
+    pub fn to_duration_from(&self, start: DateTime<Utc>) -> Option<Duration> {
+        match self {
+            SendFrequency::Custom(epoch) => {
+                epoch.to_duration_checked().ok()?;
+                Some(epoch.to_duration_from(start.naive_utc()))
+            }
+            _ => self.to_duration(),
+        }
+    }
 }
 
 /// Represents a notification to be sent for an event.
@@ -52,12 +421,18 @@ pub struct EventNotify {
     id: Vec<u8>,
     /// The event for which the notification is scheduled.
     scheduled_event: Event,
+    /// The storage key of the scheduled event's id, indexed so notifications
+    /// can be queried by event (see `StructsyStore::notifies_for_event`).
+    #[index(mode = "cluster")]
+    event_key: String,
     /// The method of notification delivery.
     delivery_method: TransportMethod,
     /// The frequency of notification delivery.
     delivery_frequency: SendFrequency,
     /// The recipients of the notification.
     recipients: Vec<Recipient>,
+    /// The ids of muted recipients, who stay listed but are not sent to.
+    muted_recipients: Vec<String>,
     /// The trigger for the notification (not the event trigger).
     notify_trigger: SignalTrigger,
     /// The start date of the notification.
@@ -66,9 +441,29 @@ pub struct EventNotify {
     created_at: DateTime<Utc>,
     /// The date and time when the notification was last updated.
     last_updated: DateTime<Utc>,
+    /// Whether the notification is sent; a disabled notification is paused.
+    enabled: bool,
+    /// How long, in seconds, after the end of the event a final send may fire.
+    grace_period: Option<i64>,
+    /// The most recipients the notification may have, guarding against
+    /// accidental mass sends.
+    max_recipients: Option<u64>,
+}
+
+/// Queries stored notifications through their indexed fields.
+#[queries(EventNotify)]
+pub(crate) trait EventNotifyQuery {
+    /// Matches the notifications of the event stored under `event_key`.
+    fn by_event_key(self, event_key: String) -> Self;
 }
 
 impl EventNotify {
+    /// Creates an enabled notification for `scheduled_event`.
+    ///
+    /// # Returns
+    ///
+    /// `NotifyError::InvalidRecipient` for the first recipient that cannot be
+    /// delivered to through `delivery_method` (see `Recipient::validate_for`).
     pub fn new(
         scheduled_event: Event,
         delivery_method: TransportMethod,
@@ -76,31 +471,120 @@ impl EventNotify {
         recipients: Vec<Recipient>,
         notify_trigger: SignalTrigger,
         start_date: DateTime<Utc>,
-    ) -> Self {
+    ) -> Result<Self, NotifyError> {
+        for recipient in &recipients {
+            recipient.validate_for(&delivery_method)?;
+        }
         let id = crate::models::uid::GlobalId::new("NTFY").to_vec();
         let created_at = crate::utils::get_current_datetime_utc();
         let last_updated = created_at;
 
-        EventNotify {
+        Ok(EventNotify {
             id,
+            event_key: crate::storage::event_key(scheduled_event.id()),
             scheduled_event,
             delivery_method,
             delivery_frequency,
             recipients,
+            muted_recipients: Vec::new(),
             notify_trigger,
             start_date,
             created_at,
             last_updated,
-        }
+            enabled: true,
+            grace_period: None,
+            max_recipients: None,
+        })
     }
 
-    /// Adds a new recipient to the notification list.
+    /// Returns true if the notification is enabled, i.e. not paused.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enables or pauses the notification.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.last_updated = crate::utils::get_current_datetime_utc();
+    }
+
+    /// Adds a new recipient to the notification list after checking that it is
+    /// deliverable through the notification's delivery method (see
+    /// `Recipient::validate_for`).
     ///
     /// # Arguments
     ///
     /// * `recipient` - The recipient to add to the notification list.
-    pub fn add_recipient(&mut self, recipient: Recipient) {
-        self.recipients.push(recipient);
+    ///
+    /// # Returns
+    ///
+    /// `NotifyError::InvalidRecipient` if the recipient was rejected; the
+    /// recipient list is left unchanged in that case.
+    pub fn add_recipient(&mut self, recipient: Recipient) -> Result<(), NotifyError> {
+        self.add_recipients(vec![recipient])
+    }
+
+    /// Adds a new recipient to the notification list like `add_recipient`,
+    /// reporting a rejected recipient as an `AppError`.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient` - The recipient to add to the notification list.
+    ///
+    /// # Returns
+    ///
+    /// An error describing why the recipient was rejected; the recipient list
+    /// is left unchanged in that case.
+    pub fn try_add_recipient(&mut self, recipient: Recipient) -> Result<(), AppError> {
+        Ok(self.add_recipient(recipient)?)
+    }
+
+    /// Adds several recipients to the notification list, all or none.
+    ///
+    /// Every recipient is checked like in `add_recipient`, and together they
+    /// must fit under the recipient cap (see `set_max_recipients`).
+    ///
+    /// # Returns
+    ///
+    /// `NotifyError::InvalidRecipient` for the first rejected recipient, or
+    /// `NotifyError::TooManyRecipients` if the cap would be exceeded; the
+    /// recipient list is left unchanged in either case.
+    pub fn add_recipients(
+        &mut self,
+        recipients: impl IntoIterator<Item = Recipient>,
+    ) -> Result<(), NotifyError> {
+        let recipients: Vec<Recipient> = recipients.into_iter().collect();
+        for recipient in &recipients {
+            recipient.validate_for(&self.delivery_method)?;
+        }
+        self.check_capacity(recipients.len())?;
+        self.recipients.extend(recipients);
+        self.last_updated = crate::utils::get_current_datetime_utc();
+        Ok(())
+    }
+
+    /// Returns the most recipients the notification may have, if capped.
+    pub fn max_recipients(&self) -> Option<usize> {
+        self.max_recipients.map(|max| max as usize)
+    }
+
+    /// Sets or clears the cap on the number of recipients.
+    ///
+    /// Recipients already on the list are kept even if they exceed the new cap;
+    /// only further additions are refused.
+    pub fn set_max_recipients(&mut self, max_recipients: Option<usize>) {
+        self.max_recipients = max_recipients.map(|max| max as u64);
+        self.last_updated = crate::utils::get_current_datetime_utc();
+    }
+
+    /// Checks that `extra` more recipients fit under the recipient cap.
+    fn check_capacity(&self, extra: usize) -> Result<(), NotifyError> {
+        match self.max_recipients() {
+            Some(max) if self.recipients.len().saturating_add(extra) > max => {
+                Err(NotifyError::TooManyRecipients { max })
+            }
+            _ => Ok(()),
+        }
     }
 
     /// Removes a recipient from the notification list.
@@ -110,6 +594,58 @@ impl EventNotify {
     /// * `recipient_id` - The ID of the recipient to remove.
     pub fn remove_recipient(&mut self, recipient_id: &str) {
         self.recipients.retain(|r| r.recipient_id != recipient_id);
+        self.muted_recipients.retain(|muted| muted != recipient_id);
+    }
+
+    /// Mutes a recipient: they stay on the notification list but are not sent
+    /// to until unmuted.
+    ///
+    /// # Returns
+    ///
+    /// True if the recipient is on the list and was not muted yet.
+    pub fn mute_recipient(&mut self, recipient_id: &str) -> bool {
+        let listed = self
+            .recipients
+            .iter()
+            .any(|r| r.recipient_id == recipient_id);
+        if !listed || self.is_muted(recipient_id) {
+            return false;
+        }
+        self.muted_recipients.push(recipient_id.to_string());
+        self.last_updated = crate::utils::get_current_datetime_utc();
+        true
+    }
+
+    /// Unmutes a recipient, returning true if they were muted.
+    pub fn unmute_recipient(&mut self, recipient_id: &str) -> bool {
+        let before = self.muted_recipients.len();
+        self.muted_recipients.retain(|muted| muted != recipient_id);
+        let unmuted = self.muted_recipients.len() != before;
+        if unmuted {
+            self.last_updated = crate::utils::get_current_datetime_utc();
+        }
+        unmuted
+    }
+
+    /// Returns true if the recipient with `recipient_id` is muted.
+    pub fn is_muted(&self, recipient_id: &str) -> bool {
+        self.muted_recipients
+            .iter()
+            .any(|muted| muted == recipient_id)
+    }
+
+    /// Returns the recipients that are sent to, i.e. the ones not muted.
+    pub fn active_recipients(&self) -> Vec<&Recipient> {
+        self.recipients
+            .iter()
+            .filter(|r| !self.is_muted(&r.recipient_id))
+            .collect()
+    }
+
+    /// Returns true if no one would receive the notification, either because
+    /// it has no recipients or because every recipient is muted.
+    pub fn is_effectively_silent(&self) -> bool {
+        self.active_recipients().is_empty()
     }
 
     /// Updates the details of a recipient in the notification list.
@@ -118,7 +654,19 @@ impl EventNotify {
     ///
     /// * `recipient_id` - The ID of the recipient to update.
     /// * `new_recipient` - The updated details of the recipient.
-    pub fn update_recipient(&mut self, recipient_id: &str, new_recipient: Recipient) {
+    ///
+    /// # Returns
+    ///
+    /// `NotifyError::InvalidRecipient` if the new recipient is not deliverable
+    /// through the notification's delivery method (see
+    /// `Recipient::validate_for`); the recipient list is left unchanged in
+    /// that case.
+    pub fn update_recipient(
+        &mut self,
+        recipient_id: &str,
+        new_recipient: Recipient,
+    ) -> Result<(), NotifyError> {
+        new_recipient.validate_for(&self.delivery_method)?;
         if let Some(index) = self
             .recipients
             .iter()
@@ -126,6 +674,7 @@ impl EventNotify {
         {
             self.recipients[index] = new_recipient;
         }
+        Ok(())
     }
 
     /// Changes the notification frequency and adjusts the start date accordingly.
@@ -153,36 +702,84 @@ impl EventNotify {
         self.delivery_frequency = deliver_frequency;
 
         // Calculate new start date based on the frequency
-        self.start_date = match self.delivery_frequency {
-            SendFrequency::OnTrigger => start_date.to_utc(),
-            SendFrequency::DayPrior => start_date,
-            SendFrequency::Daily => {
-                from_duration_to_datetime(start_date, Duration::try_days(1).unwrap())
-            }
-            SendFrequency::Weekly => {
-                from_duration_to_datetime(start_date, Duration::try_weeks(1).unwrap())
-            }
-            SendFrequency::BiWeekly => {
-                from_duration_to_datetime(start_date, Duration::try_weeks(2).unwrap())
-            }
-            SendFrequency::Monthly => {
-                from_duration_to_datetime(start_date, Duration::try_days(30).unwrap())
-            }
-            SendFrequency::Quarterly => {
-                from_duration_to_datetime(start_date, Duration::try_days(90).unwrap())
-            }
+        self.start_date = match self.delivery_frequency.to_duration_from(start_date) {
+            Some(interval) => from_duration_to_datetime(start_date, interval),
+            None => start_date,
         };
     }
 
+    /// Brings the notification back in line with its scheduled event, e.g.
+    /// after the event's epoch or start date was edited.
+    ///
+    /// A `Custom` frequency is replaced with the scheduled event's epoch, and
+    /// `start_date` is recomputed from the event's `start_datetime` the same
+    /// way `edit_delivery_frequency` does. Other frequencies are kept as is.
+    pub fn resync_to_event(&mut self) {
+        let mut frequency = self.delivery_frequency.clone();
+        if let SendFrequency::Custom(epoch) = &mut frequency {
+            *epoch = self.scheduled_event.epoch;
+        }
+        self.edit_delivery_frequency(self.scheduled_event.start_datetime, frequency);
+        self.last_updated = crate::utils::get_current_datetime_utc();
+    }
+
     /// Sets a new event for notification.
     ///
     /// # Arguments
     ///
     /// * `event` - The new event details.
     pub fn set_event(&mut self, event: Event) {
+        self.event_key = crate::storage::event_key(event.id());
         self.scheduled_event = event;
     }
 
+    /// Returns the event the notification is scheduled for.
+    pub fn scheduled_event(&self) -> &Event {
+        &self.scheduled_event
+    }
+
+    /// Returns the id of the event the notification is scheduled for, the key
+    /// used to look notifications up by event in storage.
+    pub fn event_id(&self) -> &[u8] {
+        self.scheduled_event.id()
+    }
+
+    /// Returns the method the notification is delivered through.
+    pub fn delivery_method(&self) -> &TransportMethod {
+        &self.delivery_method
+    }
+
+    /// Returns how often the notification is sent.
+    pub fn delivery_frequency(&self) -> &SendFrequency {
+        &self.delivery_frequency
+    }
+
+    /// Returns the date the notification starts sending from.
+    pub fn start_date(&self) -> DateTime<Utc> {
+        self.start_date
+    }
+
+    /// Returns the date and time when the notification was created.
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns the date and time when the notification was last updated.
+    pub fn last_updated(&self) -> DateTime<Utc> {
+        self.last_updated
+    }
+
+    /// Returns a one-line, redacted summary of how the notification is delivered.
+    pub(crate) fn delivery_summary(&self) -> String {
+        format!(
+            "{} {:?}, {} recipient(s){}",
+            self.delivery_method.channel_name(),
+            self.delivery_frequency,
+            self.recipients.len(),
+            if self.enabled { "" } else { ", disabled" }
+        )
+    }
+
     /// Returns a list of all recipients for this notification.
     pub fn list_recipients(&self) -> Vec<&Recipient> {
         self.recipients.iter().collect()
@@ -199,17 +796,467 @@ impl EventNotify {
         )
     }
 
-    // Calculates the remaining number of notification triggers left, until the end of the event's life.
-    // usage: get_trigger_count(Utc::now().naive_utc()),
-    // pub fn get_trigger_count(&self, now: NaiveDateTime) -> i64 {
-    //     let time_since_start = now.signed_duration_since(self.event.start_time);
-    //     let time_since_last_trigger =
-    //         time_since_start.num_seconds() % self.event.signal_trigger.interval;
-    //     let remaining_time = self.event.signal_trigger.interval - time_since_last_trigger;
-    //     let notify_trigger_count = remaining_time / self.event.signal_trigger.interval;
+    /// Returns the first send strictly after `after`, or `None` once the
+    /// notification has no sends left.
+    ///
+    /// Fixed-interval frequencies (`Daily` through `Quarterly`) send at the
+    /// `notify_trigger` time of day, every interval counted from `start_date`;
+    /// `Custom` sends follow the occurrences of its epoch from there instead, so
+    /// monthly sends keep their day of the month. Sends go up to and including
+    /// the scheduled event's `end_datetime`. `OnTrigger`, `DayPrior` and
+    /// `LeadTime` send `lead_time()` before each occurrence of
+    /// the scheduled event, skipping sends before `start_date`. With a grace
+    /// period, the first send past the end of the event is also kept if it falls
+    /// within `[end_datetime, end_datetime + grace_period]`. A disabled
+    /// notification has no sends.
+    pub fn next_send(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if !self.enabled {
+            return None;
+        }
+        let next = self.unbounded_next_send(after)?;
+        if self.is_within_event(next) {
+            return Some(next);
+        }
+
+        // Past the end of the event only one final send, inside the grace window.
+        let end = self.scheduled_event.end_datetime;
+        let grace_end = end.checked_add_signed(self.grace_period()?)?;
+        let final_send = match self.delivery_frequency.to_duration() {
+            Some(_) => self.unbounded_next_send(end)?,
+            None => self.unbounded_next_send(end - self.lead_time() - Duration::nanoseconds(1))?,
+        };
+        (next == final_send && end <= next && next <= grace_end).then_some(next)
+    }
+
+    /// Returns the first send strictly after `after`, ignoring the end of the
+    /// scheduled event.
+    fn unbounded_next_send(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.delivery_frequency.to_duration() {
+            Some(interval) => {
+                let anchor = self.at_trigger_time(self.start_date);
+                if after < anchor {
+                    return Some(anchor);
+                }
+                if let SendFrequency::Custom(epoch) = &self.delivery_frequency {
+                    return epoch.next_occurrence(anchor, after);
+                }
+                let elapsed = after.signed_duration_since(anchor).num_seconds();
+                let steps = elapsed / interval.num_seconds() + 1;
+                anchor.checked_add_signed(Duration::try_seconds(
+                    interval.num_seconds().checked_mul(steps)?,
+                )?)
+            }
+            None => {
+                let lead = self.lead_time();
+                let event = &self.scheduled_event;
+                let mut occurrence = event
+                    .epoch
+                    .next_occurrence(event.start_datetime, after.checked_add_signed(lead)?)?;
+                while occurrence - lead < self.start_date {
+                    occurrence = event
+                        .epoch
+                        .next_occurrence(event.start_datetime, occurrence)?;
+                }
+                Some(occurrence - lead)
+            }
+        }
+    }
+
+    /// Returns true if `send` belongs to the regular schedule, i.e. is not past
+    /// the end of the scheduled event.
+    fn is_within_event(&self, send: DateTime<Utc>) -> bool {
+        let end = self.scheduled_event.end_datetime;
+        match self.delivery_frequency.to_duration() {
+            Some(_) => send <= end,
+            None => send + self.lead_time() < end,
+        }
+    }
+
+    /// Returns how long after the end of the scheduled event a final send may
+    /// still fire, if a grace period is set.
+    pub fn grace_period(&self) -> Option<Duration> {
+        self.grace_period.and_then(Duration::try_seconds)
+    }
+
+    /// Sets or clears the grace period after the end of the scheduled event.
+    pub fn set_grace_period(&mut self, grace_period: Option<Duration>) {
+        self.grace_period = grace_period.map(|grace_period| grace_period.num_seconds());
+        self.last_updated = crate::utils::get_current_datetime_utc();
+    }
 
-    //     notify_trigger_count
-    // }
+    /// Returns how far before each event trigger the notification fires, e.g.
+    /// 24 hours for `DayPrior`.
+    ///
+    /// `OnTrigger` fires with the trigger itself, and fixed-interval frequencies
+    /// (`Daily` through `Quarterly`) are not tied to triggers; both have a zero
+    /// lead time. A negative or out of range `LeadTime` is treated as zero.
+    pub fn lead_time(&self) -> Duration {
+        match self.delivery_frequency {
+            SendFrequency::DayPrior => Duration::try_days(1).expect("1 day"),
+            SendFrequency::LeadTime(seconds) => {
+                Duration::try_seconds(seconds.max(0)).unwrap_or_else(Duration::zero)
+            }
+            _ => Duration::zero(),
+        }
+    }
+
+    /// Checks that the delivery frequency fits the scheduled event.
+    ///
+    /// The frequency's interval (or, for trigger-relative frequencies, its lead
+    /// time) must not be longer than the event's epoch period nor its total
+    /// span; e.g. a `Quarterly` reminder for a `SingleDay` event would never
+    /// line up with the event.
+    ///
+    /// # Returns
+    ///
+    /// `AppError::NotificationError` describing the mismatch, which is also
+    /// logged as a warning.
+    pub fn check_frequency_coherence(&self) -> Result<(), AppError> {
+        let frequency = self
+            .delivery_frequency
+            .to_duration()
+            .unwrap_or_else(|| self.lead_time());
+        let event = &self.scheduled_event;
+        let span = event
+            .end_datetime
+            .signed_duration_since(event.start_datetime);
+        let period = event.epoch.to_duration_checked()?;
+
+        let limit = if span < period {
+            ("event span", span)
+        } else {
+            ("epoch period", period)
+        };
+        if frequency > limit.1 {
+            let message = format!(
+                "{:?} frequency ({} days) is longer than the {} ({} days) of event {:?}",
+                self.delivery_frequency,
+                frequency.num_days(),
+                limit.0,
+                limit.1.num_days(),
+                event.title
+            );
+            log::warn!("{}", message);
+            return Err(AppError::NotificationError(message));
+        }
+        Ok(())
+    }
+
+    /// Returns the next send after `after` like `next_send`, moved off the
+    /// weekend: a send on Saturday or Sunday rolls to the following Monday at
+    /// the same time of day.
+    ///
+    /// The rolled send is not checked against the event's end again.
+    pub fn next_business_day_send(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        use chrono::{Datelike, Weekday};
+
+        let next = self.next_send(after)?;
+        let days = match next.weekday() {
+            Weekday::Sat => 2,
+            Weekday::Sun => 1,
+            _ => 0,
+        };
+        next.checked_add_signed(Duration::try_days(days)?)
+    }
+
+    /// Returns every send in `[from, to)`, in order.
+    ///
+    /// Sends follow `next_send`: they are spaced by the delivery frequency, land
+    /// on the `notify_trigger` time of day for fixed-interval frequencies, never
+    /// go past the scheduled event's `end_datetime` (apart from one final send
+    /// within the grace period), and are empty while the notification is
+    /// disabled.
+    pub fn schedule_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        std::iter::successors(self.next_send(from - Duration::nanoseconds(1)), |last| {
+            self.next_send(*last)
+        })
+        .take_while(|send| *send < to)
+        .collect()
+    }
+
+    /// Returns the sends a scheduler missed while it was down, i.e. every send
+    /// in `(last_run, now]`, in order, so they can be replayed.
+    ///
+    /// Sends follow `next_send`, so nothing is returned while the notification
+    /// is disabled or past the scheduled event's `end_datetime` and its grace
+    /// period.
+    ///
+    /// # Arguments
+    ///
+    /// * `last_run` - When the scheduler last ran; sends at this instant were
+    ///   already handled.
+    /// * `now` - The current time; a send at this instant is included.
+    pub fn missed_sends(&self, last_run: DateTime<Utc>, now: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        std::iter::successors(self.next_send(last_run), |last| self.next_send(*last))
+            .take_while(|send| *send <= now)
+            .collect()
+    }
+
+    /// Returns every scheduled send of the notification, in order.
+    pub fn sends(&self) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        std::iter::successors(self.next_send(DateTime::<Utc>::MIN_UTC), |last| {
+            self.next_send(*last)
+        })
+    }
+
+    /// Returns how many sends the notification makes over its whole schedule,
+    /// from `start_date` up to the scheduled event's `end_datetime`, e.g. to
+    /// estimate delivery costs.
+    ///
+    /// Sends follow `next_send` without the grace period. A disabled
+    /// notification makes no sends.
+    ///
+    /// # Returns
+    ///
+    /// The number of sends, or `-1` for a trigger-relative frequency
+    /// (`OnTrigger`, `DayPrior`, `LeadTime`) or a `Custom` one tied to an
+    /// unbounded event, one ending at `DateTime::<Utc>::MAX_UTC`, whose sends
+    /// cannot be counted.
+    pub fn total_sends(&self) -> i64 {
+        if !self.enabled {
+            return 0;
+        }
+        let end = self.scheduled_event.end_datetime;
+        match self.delivery_frequency.to_duration() {
+            Some(interval) => {
+                let anchor = self.at_trigger_time(self.start_date);
+                if anchor > end {
+                    return 0;
+                }
+                if let SendFrequency::Custom(epoch) = &self.delivery_frequency {
+                    let Some(until) = end.checked_add_signed(Duration::nanoseconds(1)) else {
+                        return -1;
+                    };
+                    return epoch.occurrences(anchor, until).count() as i64;
+                }
+                end.signed_duration_since(anchor).num_seconds() / interval.num_seconds() + 1
+            }
+            None if end == DateTime::<Utc>::MAX_UTC => -1,
+            None => self
+                .sends()
+                .take_while(|send| self.is_within_event(*send))
+                .count() as i64,
+        }
+    }
+
+    /// Moves `datetime` to the `notify_trigger` time of day on the same date.
+    fn at_trigger_time(&self, datetime: DateTime<Utc>) -> DateTime<Utc> {
+        datetime
+            .date_naive()
+            .and_time(self.notify_trigger.time.to_naive_time())
+            .and_utc()
+    }
+
+    /// Sends `message` to every active recipient through the sender registered
+    /// for the notification's delivery method (see `dispatch`).
+    ///
+    /// # Returns
+    ///
+    /// One result per active recipient, in the order of `recipients`; a failed
+    /// delivery does not stop the remaining ones.
+    pub async fn trigger_notification(
+        &self,
+        registry: &TransportRegistry,
+        message: &str,
+    ) -> Vec<Result<(), NotifyError>> {
+        let recipients = self.active_recipients();
+        let mut results = Vec::with_capacity(recipients.len());
+        for recipient in recipients {
+            results.push(dispatch(registry, &self.delivery_method, recipient, message).await);
+        }
+        results
+    }
+
+    /// Returns how many times the notification will still fire after `now`, up
+    /// to and including the scheduled event's `end_datetime`.
+    ///
+    /// The count is driven by the notification's own `notify_trigger`, not the
+    /// event's `signal_trigger`: fires start at the first `notify_trigger` fire
+    /// at or after `start_date` on the trigger's fixed series (see
+    /// `SignalTrigger::next_occurrence`). A trigger without a positive interval
+    /// fires once instead, at its time of day on the date of `start_date` if
+    /// that is not before `start_date`. Returns 0 once the event has ended.
+    pub fn remaining_triggers(&self, now: DateTime<Utc>) -> i64 {
+        let end = self.scheduled_event.end_datetime;
+        let interval = self.notify_trigger.interval_seconds;
+        if interval <= 0 {
+            let fire = self.at_trigger_time(self.start_date);
+            return i64::from(self.start_date <= fire && now < fire && fire <= end);
+        }
+        let Some(first) = self
+            .notify_trigger
+            .occurrences(
+                self.start_date,
+                end.checked_add_signed(Duration::nanoseconds(1))
+                    .unwrap_or(end),
+            )
+            .next()
+        else {
+            return 0;
+        };
+
+        let seconds_from_first = |at: DateTime<Utc>| at.signed_duration_since(first).num_seconds();
+        let last = seconds_from_first(end) / interval;
+        let next = if now < first {
+            0
+        } else {
+            seconds_from_first(now) / interval + 1
+        };
+        (last - next + 1).max(0)
+    }
+}
+
+/// Summarizes the state of the notifications at `now` in one log line.
+///
+/// A notification is *due* if it has a send in the minute up to `now`,
+/// `(now - 1 minute, now]`, *paused* if it is disabled, *scheduled* if its next
+/// send is later, and *finished* if it has no sends left. The line ends with
+/// the earliest upcoming send across all notifications.
+///
+/// # Examples
+///
+/// ```
+/// use event_pulse::models::notify::tick_status;
+/// use chrono::{TimeZone, Utc};
+///
+/// let now = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+/// assert_eq!(
+///     tick_status(&[], now),
+///     "tick 2024-01-01T09:00:00+00:00: 0 due, 0 paused, 0 scheduled, 0 finished, next send none"
+/// );
+/// ```
+pub fn tick_status(notifies: &[EventNotify], now: DateTime<Utc>) -> String {
+    let tick_start = now - Duration::try_minutes(1).expect("1 minute");
+    let (mut due, mut paused, mut scheduled, mut finished) = (0, 0, 0, 0);
+
+    for notify in notifies {
+        if !notify.is_enabled() {
+            paused += 1;
+        } else if notify.next_send(tick_start).is_some_and(|send| send <= now) {
+            due += 1;
+        } else if notify.next_send(now).is_some() {
+            scheduled += 1;
+        } else {
+            finished += 1;
+        }
+    }
+    let next_send = notifies
+        .iter()
+        .filter_map(|notify| notify.next_send(now))
+        .min();
+
+    format!(
+        "tick {}: {} due, {} paused, {} scheduled, {} finished, next send {}",
+        now.to_rfc3339(),
+        due,
+        paused,
+        scheduled,
+        finished,
+        next_send.map_or_else(|| "none".to_string(), |send| send.to_rfc3339())
+    )
+}
+
+/// Collapses sends of notifications scheduled for the same event that fall
+/// within `window` of each other into a single send.
+///
+/// Only sends in `[from, to)` are considered (see
+/// `EventNotify::schedule_between`), which bounds the work for long-lived or
+/// unbounded events. Sends are walked in chronological order per event; a send
+/// is kept only if it is more than `window` after the last kept send for that
+/// event. Returns `(event_id, send)` pairs ordered by send time, then event id.
+pub fn dedupe_sends(
+    notifies: &[EventNotify],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    window: Duration,
+) -> Vec<(Vec<u8>, DateTime<Utc>)> {
+    let mut sends_by_event: BTreeMap<Vec<u8>, Vec<DateTime<Utc>>> = BTreeMap::new();
+    for notify in notifies {
+        sends_by_event
+            .entry(notify.scheduled_event.id().to_vec())
+            .or_default()
+            .extend(notify.schedule_between(from, to));
+    }
+
+    let mut deduped = Vec::new();
+    for (event_id, mut sends) in sends_by_event {
+        sends.sort();
+        let mut last_kept: Option<DateTime<Utc>> = None;
+        for send in sends {
+            if last_kept.is_none_or(|last| send - last > window) {
+                deduped.push((event_id.clone(), send));
+                last_kept = Some(send);
+            }
+        }
+    }
+    deduped.sort_by(|(a_id, a_send), (b_id, b_send)| a_send.cmp(b_send).then(a_id.cmp(b_id)));
+    deduped
+}
+
+/// Returns the channel most notifications are delivered through, breaking
+/// ties alphabetically, or `None` if there are no notifications.
+pub fn most_common_transport(notifies: &[EventNotify]) -> Option<&'static str> {
+    let mut counts: BTreeMap<&'static str, usize> = BTreeMap::new();
+    for notify in notifies {
+        *counts
+            .entry(notify.delivery_method.channel_name())
+            .or_default() += 1;
+    }
+    // `max_by_key` keeps the last maximum, so walk the channels in reverse
+    // alphabetical order to let the alphabetically first one win ties.
+    counts
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(channel, _)| channel)
+}
+
+/// Returns the distinct channels the notifications for `event_id` are
+/// delivered through, in alphabetical order.
+pub fn methods_for_event(notifies: &[EventNotify], event_id: &[u8]) -> Vec<&'static str> {
+    notifies
+        .iter()
+        .filter(|notify| notify.event_id() == event_id)
+        .map(|notify| notify.delivery_method.channel_name())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Returns how many sends the notifications make in `(now, now + window]`,
+/// e.g. for a rate limiter sizing the next day's load.
+///
+/// Each send counts once, whatever its number of recipients; sends follow
+/// `EventNotify::missed_sends`, so disabled notifications contribute nothing.
+pub fn sends_in_next_window(
+    notifies: &[EventNotify],
+    now: DateTime<Utc>,
+    window: Duration,
+) -> usize {
+    let Some(until) = now.checked_add_signed(window) else {
+        return 0;
+    };
+    notifies
+        .iter()
+        .map(|notify| notify.missed_sends(now, until).len())
+        .sum()
+}
+
+/// Returns the number of messages one send of every notification produces,
+/// i.e. the sum of their recipient counts.
+pub fn total_recipients(notifies: &[EventNotify]) -> usize {
+    notifies.iter().map(|notify| notify.recipients.len()).sum()
+}
+
+/// Returns the number of distinct recipients across the notifications, deduped
+/// by `recipient_id`.
+pub fn unique_recipients(notifies: &[EventNotify]) -> usize {
+    notifies
+        .iter()
+        .flat_map(|notify| &notify.recipients)
+        .map(Recipient::recipient_id)
+        .collect::<HashSet<_>>()
+        .len()
 }
 
 #[test]
@@ -257,7 +1304,7 @@ fn test_edit_delivery_frequency() {
         utc_time.clone(),
         crate::models::time::from_duration_to_datetime(
             utc_time.clone(),
-            crate::models::epoch::Epoch::to_duration(&parsed_epoch),
+            parsed_epoch.to_duration_from(utc_time.naive_utc()),
         ),
     );
 
@@ -268,7 +1315,8 @@ fn test_edit_delivery_frequency() {
         vec![recipient],
         notify_trigger,
         utc_time,
-    );
+    )
+    .unwrap();
 
     // Test with different frequencies
     event_notify.edit_delivery_frequency(start_date, SendFrequency::DayPrior);
@@ -286,3 +1334,1133 @@ fn test_edit_delivery_frequency() {
         start_date + Duration::try_weeks(1).unwrap()
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{decimal::Money, time::MilitaryTime, CalendarData, Epoch};
+    use chrono::TimeZone;
+
+    fn weekly_event(start: DateTime<Utc>, end: DateTime<Utc>) -> Event {
+        Event::new(
+            "gym membership".to_string(),
+            Money::new(25, 0),
+            Epoch::Week(CalendarData::new(1, 1)),
+            None,
+            SignalTrigger::from_str("M09:00:00::I604800").expect("valid signal trigger"),
+            start,
+            end,
+        )
+    }
+
+    fn notify(
+        event: Event,
+        frequency: SendFrequency,
+        time: MilitaryTime,
+        start: DateTime<Utc>,
+    ) -> EventNotify {
+        let recipient = Recipient {
+            recipient_id: "test@example.com".to_string(),
+        };
+        EventNotify::new(
+            event,
+            TransportMethod::Email(recipient.clone()),
+            frequency,
+            vec![recipient],
+            SignalTrigger::new(time, crate::SECS_IN_DAY),
+            start,
+        )
+        .expect("valid recipients")
+    }
+
+    #[test]
+    fn test_recipient_redacts_email() {
+        let recipient = Recipient::new("jane.doe@example.com");
+        assert_eq!(recipient.redacted(), "j***@example.com");
+        assert_eq!(recipient.to_string(), "j***@example.com");
+        assert_eq!(
+            format!("{:?}", recipient),
+            "Recipient { recipient_id: \"j***@example.com\" }"
+        );
+        assert_eq!(recipient.recipient_id(), "jane.doe@example.com");
+    }
+
+    #[test]
+    fn test_recipient_redacts_phone_number() {
+        let recipient = Recipient::new("+15551234567");
+        assert_eq!(recipient.redacted(), "+1********67");
+        assert_eq!(recipient.to_string(), "+1********67");
+        assert_eq!(Recipient::new("1234").redacted(), "****");
+    }
+
+    struct MockEmailSender {
+        sent: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl NotificationSender for MockEmailSender {
+        async fn send(&self, recipient: &Recipient, message: &str) -> Result<(), NotifyError> {
+            if recipient.recipient_id().starts_with("bounce") {
+                return Err(NotifyError::DeliveryFailed {
+                    recipient: recipient.to_string(),
+                    reason: "mailbox full".to_string(),
+                });
+            }
+            self.sent
+                .lock()
+                .unwrap()
+                .push(format!("{} <- {}", recipient.recipient_id(), message));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_accessors() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let event = weekly_event(start, end);
+        let notify = notify(
+            event.clone(),
+            SendFrequency::BiWeekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        assert_eq!(notify.delivery_frequency(), &SendFrequency::BiWeekly);
+        assert_eq!(notify.start_date(), start);
+        assert_eq!(notify.scheduled_event(), &event);
+        assert_eq!(notify.delivery_method().channel_name(), "email");
+        assert_eq!(notify.created_at(), notify.last_updated());
+    }
+
+    #[test]
+    fn test_send_frequency_to_duration() {
+        let cases = [
+            (SendFrequency::OnTrigger, None),
+            (SendFrequency::DayPrior, None),
+            (SendFrequency::LeadTime(3600), None),
+            (SendFrequency::Daily, Duration::try_days(1)),
+            (SendFrequency::Weekly, Duration::try_days(7)),
+            (SendFrequency::BiWeekly, Duration::try_days(14)),
+            (SendFrequency::Monthly, Duration::try_days(30)),
+            (SendFrequency::Quarterly, Duration::try_days(90)),
+            (
+                SendFrequency::Custom(Epoch::Day(CalendarData::new(3, 1))),
+                Duration::try_days(3),
+            ),
+        ];
+        for (frequency, expected) in cases {
+            assert_eq!(frequency.to_duration(), expected, "{:?}", frequency);
+        }
+    }
+
+    #[test]
+    fn test_total_sends_weekly_over_four_weeks() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = start + Duration::try_weeks(4).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        assert_eq!(notify.total_sends(), 4);
+        assert_eq!(
+            notify.total_sends(),
+            notify.schedule_between(start, end).len() as i64
+        );
+
+        notify.set_enabled(false);
+        assert_eq!(notify.total_sends(), 0);
+    }
+
+    #[test]
+    fn test_total_sends_on_trigger() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let end = start + Duration::try_weeks(4).unwrap();
+        let time = MilitaryTime::new(9, 0, 0);
+        let bounded = notify(
+            weekly_event(start, end),
+            SendFrequency::OnTrigger,
+            time.clone(),
+            start,
+        );
+        // Weekly occurrences on Jan 1, 8, 15 and 22; Jan 29 is the end itself.
+        assert_eq!(bounded.total_sends(), 4);
+
+        let unbounded = notify(
+            weekly_event(start, DateTime::<Utc>::MAX_UTC),
+            SendFrequency::OnTrigger,
+            time,
+            start,
+        );
+        assert_eq!(unbounded.total_sends(), -1);
+    }
+
+    #[test]
+    fn test_total_sends_custom_unbounded() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let notify = notify(
+            weekly_event(start, DateTime::<Utc>::MAX_UTC),
+            SendFrequency::Custom(Epoch::Month(CalendarData::new(1, 1))),
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        assert_eq!(notify.total_sends(), -1);
+    }
+
+    #[test]
+    fn test_resync_to_event_follows_epoch_change() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut event = weekly_event(start, end);
+        let mut notify = notify(
+            event.clone(),
+            SendFrequency::Custom(event.epoch),
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        notify.resync_to_event();
+        assert_eq!(notify.start_date(), start + Duration::try_weeks(1).unwrap());
+
+        event.epoch = Epoch::Day(CalendarData::new(3, 1));
+        notify.set_event(event.clone());
+        let before = notify.last_updated();
+        notify.resync_to_event();
+
+        assert_eq!(
+            notify.delivery_frequency(),
+            &SendFrequency::Custom(event.epoch)
+        );
+        assert_eq!(notify.start_date(), start + Duration::try_days(3).unwrap());
+        assert!(notify.last_updated() >= before);
+    }
+
+    #[test]
+    fn test_try_add_recipient_accepts_valid_email() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        assert_eq!(
+            notify.try_add_recipient(Recipient::new("jane@example.com")),
+            Ok(())
+        );
+        assert!(notify
+            .list_recipients()
+            .iter()
+            .any(|r| r.recipient_id() == "jane@example.com"));
+    }
+
+    #[test]
+    fn test_try_add_recipient_rejects_phone_number_for_email() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        let before = notify.list_recipients().len();
+
+        assert_eq!(
+            notify.try_add_recipient(Recipient::new("+15551234567")),
+            Err(AppError::NotificationError(
+                "+1********67 is not a valid email recipient".to_string()
+            ))
+        );
+        assert_eq!(notify.list_recipients().len(), before);
+        assert_eq!(
+            TransportMethod::Sms(Recipient::new("+15551234567"))
+                .validate_recipient(&Recipient::new("+1 555-123-4567")),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_recipients() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let method = TransportMethod::Email(Recipient::new("ops@example.com"));
+        let new = |recipients| {
+            EventNotify::new(
+                weekly_event(start, end),
+                method.clone(),
+                SendFrequency::Weekly,
+                recipients,
+                SignalTrigger::new(MilitaryTime::new(9, 0, 0), crate::SECS_IN_DAY),
+                start,
+            )
+        };
+
+        assert_eq!(
+            new(vec![
+                Recipient::new("jane@example.com"),
+                Recipient::new("+15551234567"),
+            ])
+            .map(|notify| notify.list_recipients().len()),
+            Err(NotifyError::InvalidRecipient {
+                recipient: "+1********67".to_string(),
+                channel: "email",
+            })
+        );
+        assert_eq!(
+            new(vec![Recipient::new("jane@example.com")])
+                .map(|notify| notify.list_recipients().len()),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn test_validate_for_email_and_sms() {
+        let email = TransportMethod::Email(Recipient::new("ops@example.com"));
+        let sms = TransportMethod::Sms(Recipient::new("+15551234567"));
+
+        assert_eq!(
+            Recipient::new("jane@example.com").validate_for(&email),
+            Ok(())
+        );
+        assert_eq!(
+            Recipient::new("not-an-email").validate_for(&email),
+            Err(NotifyError::InvalidRecipient {
+                recipient: "no********il".to_string(),
+                channel: "email",
+            })
+        );
+        assert_eq!(Recipient::new("+15551234567").validate_for(&sms), Ok(()));
+        assert!(Recipient::new("555-CALL-NOW").validate_for(&sms).is_err());
+    }
+
+    #[test]
+    fn test_validate_for_handles() {
+        let slack = TransportMethod::Slack(Recipient::new("@ops"));
+        let discord = TransportMethod::Discord(Recipient::new("ops"));
+        let telegram = TransportMethod::Telegram(Recipient::new("@ops_team"));
+
+        assert!(Recipient::new("#on-call").validate_for(&slack).is_ok());
+        assert!(Recipient::new("On Call").validate_for(&slack).is_err());
+        assert!(Recipient::new("jane.doe").validate_for(&discord).is_ok());
+        assert!(Recipient::new("j").validate_for(&discord).is_err());
+        assert!(Recipient::new("@Jane_Doe").validate_for(&telegram).is_ok());
+        assert!(Recipient::new("@jane doe").validate_for(&telegram).is_err());
+    }
+
+    #[test]
+    fn test_add_and_update_recipient_reject_invalid() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        assert!(notify
+            .add_recipient(Recipient::new("not-an-email"))
+            .is_err());
+        assert!(notify
+            .update_recipient("test@example.com", Recipient::new("+15551234567"))
+            .is_err());
+        assert_eq!(
+            notify.update_recipient("test@example.com", Recipient::new("jane@example.com")),
+            Ok(())
+        );
+        let ids: Vec<_> = notify
+            .list_recipients()
+            .iter()
+            .map(|r| r.recipient_id())
+            .collect();
+        assert_eq!(ids, vec!["jane@example.com"]);
+    }
+
+    #[test]
+    fn test_max_recipients_caps_additions() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        notify.set_max_recipients(Some(2));
+        assert_eq!(notify.max_recipients(), Some(2));
+
+        // test@ is already listed, so one more fits.
+        assert_eq!(
+            notify.add_recipient(Recipient::new("jane@example.com")),
+            Ok(())
+        );
+        assert_eq!(
+            notify.add_recipient(Recipient::new("sam@example.com")),
+            Err(NotifyError::TooManyRecipients { max: 2 })
+        );
+        assert!(notify
+            .try_add_recipient(Recipient::new("sam@example.com"))
+            .is_err());
+        assert_eq!(notify.list_recipients().len(), 2);
+
+        notify.remove_recipient("jane@example.com");
+        assert_eq!(
+            notify.add_recipients([
+                Recipient::new("sam@example.com"),
+                Recipient::new("ops@example.com"),
+            ]),
+            Err(NotifyError::TooManyRecipients { max: 2 })
+        );
+        assert_eq!(notify.list_recipients().len(), 1);
+
+        notify.set_max_recipients(None);
+        assert_eq!(
+            notify.add_recipients([
+                Recipient::new("sam@example.com"),
+                Recipient::new("ops@example.com"),
+            ]),
+            Ok(())
+        );
+        assert_eq!(notify.list_recipients().len(), 3);
+    }
+
+    #[test]
+    fn test_transport_registry_dispatch() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let mut registry = TransportRegistry::new();
+        registry.register(
+            "email",
+            Box::new(MockEmailSender {
+                sent: Arc::clone(&sent),
+            }),
+        );
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        notify
+            .add_recipient(Recipient::new("bounce@example.com"))
+            .unwrap();
+        assert!(registry
+            .sender_for(&TransportMethod::Slack(Recipient::new("@jane")))
+            .is_none());
+
+        let results =
+            futures::executor::block_on(notify.trigger_notification(&registry, "Payment due"));
+        assert_eq!(results[0], Ok(()));
+        assert!(matches!(
+            results[1],
+            Err(NotifyError::DeliveryFailed { .. })
+        ));
+        assert_eq!(
+            *sent.lock().unwrap(),
+            vec!["test@example.com <- Payment due".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_trigger_notification_with_log_transport() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        notify.recipients.push(Recipient::new("ops@example.com"));
+
+        let log = Arc::new(LogTransport::new());
+        let mut registry = TransportRegistry::new();
+        registry.register("email", Box::new(Arc::clone(&log)));
+
+        let results =
+            futures::executor::block_on(notify.trigger_notification(&registry, "Payment due"));
+        assert_eq!(results, vec![Ok(()), Ok(())]);
+        assert_eq!(
+            log.messages(),
+            vec![
+                ("test@example.com".to_string(), "Payment due".to_string()),
+                ("ops@example.com".to_string(), "Payment due".to_string()),
+            ]
+        );
+
+        let results = futures::executor::block_on(
+            notify.trigger_notification(&TransportRegistry::new(), "Payment due"),
+        );
+        assert_eq!(
+            results,
+            vec![Err(NotifyError::NoTransport("email".to_string())); 2]
+        );
+    }
+
+    #[test]
+    fn test_is_effectively_silent_when_all_muted() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        notify
+            .add_recipient(Recipient::new("ops@example.com"))
+            .unwrap();
+        assert!(!notify.is_effectively_silent());
+
+        assert!(notify.mute_recipient("test@example.com"));
+        assert!(notify.mute_recipient("ops@example.com"));
+        assert!(!notify.mute_recipient("ops@example.com"));
+        assert!(notify.is_effectively_silent());
+        assert_eq!(notify.list_recipients().len(), 2);
+
+        assert!(notify.unmute_recipient("ops@example.com"));
+        assert!(!notify.is_effectively_silent());
+
+        notify.remove_recipient("test@example.com");
+        notify.remove_recipient("ops@example.com");
+        assert!(notify.is_effectively_silent());
+        // Removing a muted recipient forgets the mute.
+        notify
+            .add_recipient(Recipient::new("test@example.com"))
+            .unwrap();
+        assert!(!notify.is_effectively_silent());
+    }
+
+    #[test]
+    fn test_is_effectively_silent_when_partially_muted() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        notify
+            .add_recipient(Recipient::new("ops@example.com"))
+            .unwrap();
+        assert!(notify.mute_recipient("test@example.com"));
+        assert!(!notify.mute_recipient("unknown@example.com"));
+
+        assert!(!notify.is_effectively_silent());
+        assert_eq!(
+            notify.active_recipients(),
+            vec![&Recipient::new("ops@example.com")]
+        );
+
+        let log = Arc::new(LogTransport::new());
+        let mut registry = TransportRegistry::new();
+        registry.register("email", Box::new(Arc::clone(&log)));
+        let results =
+            futures::executor::block_on(notify.trigger_notification(&registry, "Payment due"));
+        assert_eq!(results, vec![Ok(())]);
+        assert_eq!(
+            log.messages(),
+            vec![("ops@example.com".to_string(), "Payment due".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_most_common_transport() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let email = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        let mut slack = email.clone();
+        slack.delivery_method = TransportMethod::Slack(Recipient::new("@ops"));
+
+        assert_eq!(most_common_transport(&[]), None);
+        assert_eq!(
+            most_common_transport(&[email.clone(), slack.clone(), slack.clone()]),
+            Some("slack")
+        );
+        // Ties are broken alphabetically.
+        assert_eq!(
+            most_common_transport(&[slack.clone(), email.clone()]),
+            Some("email")
+        );
+    }
+
+    #[test]
+    fn test_remaining_triggers_started_before_now() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap();
+        let notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        // Daily fires at 09:00 on Jan 6 through Jan 10.
+        let now = Utc.with_ymd_and_hms(2024, 1, 5, 12, 0, 0).unwrap();
+        assert_eq!(notify.remaining_triggers(now), 5);
+        // Before the first fire, every fire is still to come.
+        assert_eq!(
+            notify.remaining_triggers(start - Duration::try_days(3).unwrap()),
+            10
+        );
+    }
+
+    #[test]
+    fn test_remaining_triggers_after_end() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap();
+        let notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        assert_eq!(notify.remaining_triggers(end), 0);
+        assert_eq!(
+            notify.remaining_triggers(end + Duration::try_days(30).unwrap()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_remaining_triggers_unbounded() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let notify = notify(
+            weekly_event(start, DateTime::<Utc>::MAX_UTC),
+            SendFrequency::Daily,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        let now = Utc.with_ymd_and_hms(2024, 1, 5, 12, 0, 0).unwrap();
+        let remaining = notify.remaining_triggers(now);
+        assert!(remaining > 0);
+        assert_eq!(
+            notify.remaining_triggers(now + Duration::try_days(1).unwrap()),
+            remaining - 1
+        );
+    }
+
+    #[test]
+    fn test_remaining_triggers_interval_divides_span() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            MilitaryTime::new(0, 0, 0),
+            start,
+        );
+        notify.notify_trigger = SignalTrigger::new(MilitaryTime::new(0, 0, 0), 6 * 3600);
+
+        // 06:00, 12:00, 18:00 and the fire landing exactly on the end.
+        let now = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        assert_eq!(notify.remaining_triggers(now), 4);
+
+        notify.notify_trigger = SignalTrigger::new(MilitaryTime::new(0, 0, 0), 0);
+        assert_eq!(notify.remaining_triggers(now), 0);
+        assert_eq!(
+            notify.remaining_triggers(start - Duration::try_seconds(1).unwrap()),
+            1
+        );
+    }
+
+    #[test]
+    fn test_methods_for_event() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let email = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        let mut slack = email.clone();
+        slack.delivery_method = TransportMethod::Slack(Recipient::new("@ops"));
+        let other = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        let notifies = [slack, email.clone(), email.clone(), other.clone()];
+
+        assert_eq!(
+            methods_for_event(&notifies, email.event_id()),
+            vec!["email", "slack"]
+        );
+        assert_eq!(
+            methods_for_event(&notifies, other.event_id()),
+            vec!["email"]
+        );
+        assert!(methods_for_event(&notifies, b"missing").is_empty());
+    }
+
+    #[test]
+    fn test_tick_status() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 30).unwrap();
+        let due = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        let mut paused = due.clone();
+        paused.set_enabled(false);
+        let future = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            MilitaryTime::new(17, 0, 0),
+            start,
+        );
+        let finished = notify(
+            weekly_event(start, start + Duration::try_days(2).unwrap()),
+            SendFrequency::Daily,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        let status = tick_status(&[due, paused, future, finished], now);
+        assert_eq!(
+            status,
+            "tick 2024-01-10T09:00:30+00:00: 1 due, 1 paused, 1 scheduled, 1 finished, \
+             next send 2024-01-10T17:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_sends_in_next_window_daily_and_weekly() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let time = MilitaryTime::new(9, 0, 0);
+        let notifies = [
+            notify(
+                weekly_event(start, end),
+                SendFrequency::Daily,
+                time.clone(),
+                start,
+            ),
+            notify(weekly_event(start, end), SendFrequency::Weekly, time, start),
+        ];
+        let day = Duration::try_hours(24).unwrap();
+
+        // Monday Jan 8 is both a daily and a weekly send.
+        let monday = Utc.with_ymd_and_hms(2024, 1, 8, 0, 0, 0).unwrap();
+        assert_eq!(sends_in_next_window(&notifies, monday, day), 2);
+        assert_eq!(sends_in_next_window(&notifies, monday + day, day), 1);
+        // A send at `now` itself is excluded, one at `now + window` included.
+        let nine = Utc.with_ymd_and_hms(2024, 1, 8, 9, 0, 0).unwrap();
+        assert_eq!(sends_in_next_window(&notifies, nine, day), 1);
+        assert_eq!(sends_in_next_window(&[], monday, day), 0);
+    }
+
+    #[test]
+    fn test_total_and_unique_recipients() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let mut first = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        first
+            .add_recipient(Recipient::new("jane@example.com"))
+            .unwrap();
+        let mut second = first.clone();
+        second.remove_recipient("test@example.com");
+        second
+            .add_recipient(Recipient::new("sam@example.com"))
+            .unwrap();
+
+        // test@ + jane@, then jane@ + sam@.
+        let notifies = [first, second];
+        assert_eq!(total_recipients(&notifies), 4);
+        assert_eq!(unique_recipients(&notifies), 3);
+        assert_eq!(total_recipients(&[]), 0);
+        assert_eq!(unique_recipients(&[]), 0);
+    }
+
+    #[test]
+    fn test_schedule_between_weekly_over_a_month() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(8, 30, 0),
+            start,
+        );
+
+        let from = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+        let sends = notify.schedule_between(from, to);
+        assert_eq!(
+            sends,
+            vec![
+                Utc.with_ymd_and_hms(2024, 4, 5, 8, 30, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 4, 12, 8, 30, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 4, 19, 8, 30, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 4, 26, 8, 30, 0).unwrap(),
+            ]
+        );
+
+        notify.set_enabled(false);
+        assert!(notify.schedule_between(from, to).is_empty());
+    }
+
+    #[test]
+    fn test_schedule_between_stops_at_event_end() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        let notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            MilitaryTime::new(8, 30, 0),
+            start,
+        );
+
+        let sends =
+            notify.schedule_between(start, Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap());
+        assert_eq!(sends.len(), 9);
+        assert_eq!(
+            sends.last(),
+            Some(&Utc.with_ymd_and_hms(2024, 3, 9, 8, 30, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_missed_sends_after_a_day_of_downtime() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        // The scheduler last ran right after the Jan 10 send and came back
+        // a day and a bit later, at the Jan 12 send.
+        let last_run = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 12, 9, 0, 0).unwrap();
+        assert_eq!(
+            notify.missed_sends(last_run, now),
+            vec![
+                Utc.with_ymd_and_hms(2024, 1, 11, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 12, 9, 0, 0).unwrap(),
+            ]
+        );
+
+        notify.set_enabled(false);
+        assert!(notify.missed_sends(last_run, now).is_empty());
+    }
+
+    #[test]
+    fn test_next_send_fixed_interval() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        let notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        let sends: Vec<_> = notify.sends().collect();
+        assert_eq!(
+            sends,
+            vec![
+                Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 8, 9, 0, 0).unwrap(),
+            ]
+        );
+        assert_eq!(notify.next_send(sends[1]), None);
+    }
+
+    #[test]
+    fn test_custom_month_sends_do_not_drift() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 4, 2, 0, 0, 0).unwrap();
+        let frequency = SendFrequency::Custom(Epoch::Month(CalendarData::new(1, 1)));
+        let mut notify = notify(
+            weekly_event(start, end),
+            frequency.clone(),
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        // January has 31 days, so a 30-day interval would land on January 31
+        let sends: Vec<_> = notify.sends().collect();
+        assert_eq!(
+            sends,
+            vec![
+                Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 2, 1, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 4, 1, 9, 0, 0).unwrap(),
+            ]
+        );
+        assert_eq!(notify.total_sends(), 4);
+
+        notify.edit_delivery_frequency(start, frequency);
+        assert_eq!(
+            notify.start_date(),
+            Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_next_business_day_send_rolls_weekend_to_monday() {
+        // Saturday
+        let start = Utc.with_ymd_and_hms(2024, 1, 6, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            MilitaryTime::new(9, 30, 0),
+            start,
+        );
+
+        let monday = Utc.with_ymd_and_hms(2024, 1, 8, 9, 30, 0).unwrap();
+        assert_eq!(
+            notify.next_send(start),
+            Some(start + Duration::try_minutes(9 * 60 + 30).unwrap())
+        );
+        assert_eq!(notify.next_business_day_send(start), Some(monday));
+        // Sunday's send rolls to the same Monday; weekday sends are kept.
+        let sunday_send = Utc.with_ymd_and_hms(2024, 1, 7, 9, 30, 0).unwrap();
+        assert_eq!(
+            notify.next_business_day_send(sunday_send - Duration::try_hours(1).unwrap()),
+            Some(monday)
+        );
+        assert_eq!(notify.next_business_day_send(sunday_send), Some(monday));
+        assert_eq!(
+            notify.next_business_day_send(monday),
+            Some(Utc.with_ymd_and_hms(2024, 1, 9, 9, 30, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_check_frequency_coherence_accepts_daily_for_weekly_event() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let time = MilitaryTime::new(9, 0, 0);
+
+        let daily = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            time.clone(),
+            start,
+        );
+        assert_eq!(daily.check_frequency_coherence(), Ok(()));
+        let day_prior = notify(
+            weekly_event(start, end),
+            SendFrequency::DayPrior,
+            time,
+            start,
+        );
+        assert_eq!(day_prior.check_frequency_coherence(), Ok(()));
+    }
+
+    #[test]
+    fn test_check_frequency_coherence_rejects_quarterly_single_day() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut event = weekly_event(start, end);
+        event.epoch = Epoch::SingleDay;
+        let notify = notify(
+            event,
+            SendFrequency::Quarterly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        assert!(matches!(
+            notify.check_frequency_coherence(),
+            Err(AppError::NotificationError(message)) if message.contains("epoch period")
+        ));
+    }
+
+    #[test]
+    fn test_lead_time() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let event = weekly_event(start, end);
+        let time = MilitaryTime::new(9, 0, 0);
+
+        assert_eq!(
+            notify(event.clone(), SendFrequency::DayPrior, time.clone(), start).lead_time(),
+            Duration::try_hours(24).unwrap()
+        );
+        assert_eq!(
+            notify(event.clone(), SendFrequency::OnTrigger, time.clone(), start).lead_time(),
+            Duration::zero()
+        );
+        assert_eq!(
+            notify(event, SendFrequency::Weekly, time, start).lead_time(),
+            Duration::zero()
+        );
+    }
+
+    #[test]
+    fn test_next_send_three_day_lead_time() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let notify = notify(
+            weekly_event(start, end),
+            SendFrequency::LeadTime(3 * crate::SECS_IN_DAY),
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        assert_eq!(notify.lead_time(), Duration::try_hours(72).unwrap());
+        // The Jan 1 trigger would need a send before `start_date`, so the first
+        // send is 72h before the Jan 8 trigger.
+        assert_eq!(
+            notify.next_send(start),
+            Some(Utc.with_ymd_and_hms(2024, 1, 5, 9, 0, 0).unwrap())
+        );
+        assert_eq!(
+            notify.next_send(Utc.with_ymd_and_hms(2024, 1, 5, 9, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2024, 1, 12, 9, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_grace_period_allows_one_final_send() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 29, 12, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::Daily,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        let last_regular = Utc.with_ymd_and_hms(2024, 1, 29, 9, 0, 0).unwrap();
+        assert_eq!(notify.next_send(last_regular), None);
+
+        notify.set_grace_period(Some(Duration::try_days(2).unwrap()));
+        let final_send = Utc.with_ymd_and_hms(2024, 1, 30, 9, 0, 0).unwrap();
+        assert_eq!(notify.next_send(last_regular), Some(final_send));
+        assert_eq!(notify.next_send(final_send), None);
+        assert_eq!(
+            notify.schedule_between(last_regular, end + Duration::try_days(7).unwrap()),
+            vec![last_regular, final_send]
+        );
+
+        // A final send beyond the grace window is dropped.
+        notify.set_grace_period(Some(Duration::try_hours(12).unwrap()));
+        assert_eq!(notify.next_send(last_regular), None);
+    }
+
+    #[test]
+    fn test_grace_period_on_trigger_expiry() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 29, 9, 0, 0).unwrap();
+        let mut notify = notify(
+            weekly_event(start, end),
+            SendFrequency::OnTrigger,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        let last_regular = Utc.with_ymd_and_hms(2024, 1, 22, 9, 0, 0).unwrap();
+        assert_eq!(notify.next_send(last_regular), None);
+
+        // The trigger at the (exclusive) end fires as the final notice.
+        notify.set_grace_period(Some(Duration::try_hours(1).unwrap()));
+        assert_eq!(notify.next_send(last_regular), Some(end));
+        assert_eq!(notify.next_send(end), None);
+    }
+
+    #[test]
+    fn test_next_send_day_prior() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 20, 0, 0, 0).unwrap();
+        let notify = notify(
+            weekly_event(start, end),
+            SendFrequency::DayPrior,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        // The first occurrence's day-prior send falls before `start_date`.
+        let sends: Vec<_> = notify.sends().collect();
+        assert_eq!(
+            sends,
+            vec![
+                Utc.with_ymd_and_hms(2024, 1, 7, 12, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 14, 12, 0, 0).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dedupe_sends_collapses_coincident_sends() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap();
+        let event = weekly_event(start, end);
+        let morning = notify(
+            event.clone(),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+        let half_past = notify(
+            event.clone(),
+            SendFrequency::Weekly,
+            MilitaryTime::new(9, 30, 0),
+            start,
+        );
+
+        let deduped = dedupe_sends(
+            &[morning, half_past],
+            start,
+            end,
+            Duration::try_hours(1).unwrap(),
+        );
+        assert_eq!(
+            deduped,
+            vec![(
+                event.id().to_vec(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_dedupe_sends_unbounded_event() {
+        use chrono::Datelike;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let event = weekly_event(start, DateTime::<Utc>::MAX_UTC);
+        let daily = notify(
+            event.clone(),
+            SendFrequency::Daily,
+            MilitaryTime::new(9, 0, 0),
+            start,
+        );
+
+        let to = Utc.with_ymd_and_hms(2024, 1, 4, 0, 0, 0).unwrap();
+        let sends: Vec<_> = dedupe_sends(&[daily], start, to, Duration::zero())
+            .into_iter()
+            .map(|(_, send)| send.day())
+            .collect();
+        assert_eq!(sends, vec![1, 2, 3]);
+    }
+}
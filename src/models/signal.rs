@@ -1,5 +1,8 @@
+use std::cmp::Ordering;
+
 use crate::error::AppError;
 use crate::models::time::MilitaryTime;
+use chrono::{DateTime, Duration, Utc};
 use structsy::derive::PersistentEmbedded;
 
 /// Defines a designated point-in-time (MilitaryTime) and the sleep duration
@@ -90,11 +93,310 @@ impl SignalTrigger {
 
         Ok(SignalTrigger::new(time, interval_seconds))
     }
+
+    /// Parses a `sep`-separated list of signal triggers, e.g.
+    /// `"M08:00:00::I86400;M20:00:00::I86400"` with `';'`.
+    ///
+    /// Surrounding whitespace on each segment is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The list of formatted signal triggers.
+    /// * `sep` - The character separating the triggers.
+    ///
+    /// # Returns
+    ///
+    /// The parsed triggers in input order, or an `AppError::ParseError` naming the
+    /// index of the first segment that fails to parse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_pulse::models::SignalTrigger;
+    ///
+    /// let triggers = SignalTrigger::parse_list("M08:00:00::I86400; M20:00:00::I3600", ';');
+    /// assert_eq!(triggers.unwrap().len(), 2);
+    /// ```
+    pub fn parse_list(s: &str, sep: char) -> Result<Vec<SignalTrigger>, AppError> {
+        s.split(sep)
+            .enumerate()
+            .map(|(index, segment)| {
+                SignalTrigger::from_str(segment).map_err(|err| {
+                    AppError::ParseError(format!(
+                        "Invalid signal trigger at segment {} ({:?}): {}",
+                        index,
+                        segment.trim(),
+                        err
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Returns how many times the trigger fires per day, e.g. 24.0 for an hourly
+    /// interval, or 0.0 for a one-shot trigger (an interval of zero or less).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_pulse::models::{MilitaryTime, SignalTrigger};
+    ///
+    /// let weekly = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 7 * 86400);
+    /// assert_eq!(weekly.firings_per_day(), 1.0 / 7.0);
+    /// ```
+    pub fn firings_per_day(&self) -> f64 {
+        if self.interval_seconds <= 0 {
+            return 0.0;
+        }
+        crate::SECS_IN_DAY as f64 / self.interval_seconds as f64
+    }
+
+    /// Orders two triggers by whichever fires first strictly after `now`.
+    ///
+    /// Triggers that never fire again sort after those that do, which makes the
+    /// ordering suitable for a min-heap scheduler (wrapped in `Reverse`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_pulse::models::{MilitaryTime, SignalTrigger};
+    /// use chrono::{TimeZone, Utc};
+    /// use std::cmp::Ordering;
+    ///
+    /// let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    /// let in_an_hour = SignalTrigger::new(MilitaryTime::new(13, 0, 0), 86400);
+    /// let in_a_day = SignalTrigger::new(MilitaryTime::new(12, 0, 0), 86400);
+    /// assert_eq!(in_an_hour.cmp_by_next_fire(&in_a_day, now), Ordering::Less);
+    /// ```
+    pub fn cmp_by_next_fire(&self, other: &SignalTrigger, now: DateTime<Utc>) -> Ordering {
+        match (self.next_occurrence(now), other.next_occurrence(now)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    /// Returns the fires, in `[from, to)`, whose UTC instant moved because the
+    /// trigger's time of day maps to a different UTC offset in `tz` than on the
+    /// previous day, e.g. after a daylight saving time transition.
+    ///
+    /// The trigger's `time` is read as local time in `tz`, once per local day.
+    /// Days on which that local time does not exist (it falls in a DST gap) are
+    /// skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_pulse::models::{MilitaryTime, SignalTrigger};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let trigger = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 86400);
+    /// let from = Utc.with_ymd_and_hms(2024, 3, 25, 0, 0, 0).unwrap();
+    /// let to = Utc.with_ymd_and_hms(2024, 4, 5, 0, 0, 0).unwrap();
+    /// let changes = trigger.utc_offset_changes(chrono_tz::Europe::London, from, to);
+    /// assert_eq!(changes, vec![Utc.with_ymd_and_hms(2024, 3, 31, 8, 0, 0).unwrap()]);
+    /// ```
+    #[cfg(feature = "timezone")]
+    pub fn utc_offset_changes(
+        &self,
+        tz: chrono_tz::Tz,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Vec<DateTime<Utc>> {
+        use chrono::{Offset, TimeZone};
+
+        let time = self.time.to_naive_time();
+        let last_day = to.with_timezone(&tz).date_naive();
+        let mut day = from.with_timezone(&tz).date_naive();
+        let mut previous = None;
+        let mut changes = Vec::new();
+        while day <= last_day {
+            if let Some(local) = tz.from_local_datetime(&day.and_time(time)).earliest() {
+                let offset = local.offset().fix();
+                let fire = local.with_timezone(&Utc);
+                if previous.is_some_and(|previous| previous != offset) && from <= fire && fire < to
+                {
+                    changes.push(fire);
+                }
+                previous = Some(offset);
+            }
+            match day.succ_opt() {
+                Some(next) => day = next,
+                None => break,
+            }
+        }
+        changes
+    }
+
+    /// Returns the first time the trigger fires strictly after `after`.
+    ///
+    /// Every trigger fires on one fixed series: it is anchored at the trigger's
+    /// `time` of day on the Unix epoch date, 1970-01-01, and repeats every
+    /// `interval_seconds` from there. Successive calls, and `occurrences`, thus
+    /// agree on the same fires whenever they are asked. The time of day is the
+    /// anchor even when the interval is not a whole number of days, so such a
+    /// trigger moves through the day: a `M09:00:00` trigger with a 7 hour
+    /// interval fires 7 hours apart around the clock, and a weekly trigger fires
+    /// on Thursdays, the weekday of the epoch date.
+    ///
+    /// A trigger without a positive interval has no series and never fires.
+    ///
+    /// # Returns
+    ///
+    /// The next fire, or `None` if the interval is not positive or the result
+    /// is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_pulse::models::{MilitaryTime, SignalTrigger};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let trigger = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 6 * 3600);
+    /// let now = Utc.with_ymd_and_hms(2024, 1, 2, 17, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     trigger.next_occurrence(now),
+    ///     Some(Utc.with_ymd_and_hms(2024, 1, 2, 21, 0, 0).unwrap())
+    /// );
+    /// ```
+    pub fn next_occurrence(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        use chrono::Timelike;
+
+        if self.interval_seconds <= 0 {
+            return None;
+        }
+        // Whole seconds from the anchor, rounded down, so the fire found is
+        // strictly after `after` even with a sub-second remainder.
+        let anchor = i64::from(self.time.to_naive_time().num_seconds_from_midnight());
+        let elapsed = after.timestamp().checked_sub(anchor)?;
+        let steps = elapsed.div_euclid(self.interval_seconds) + 1;
+        let fire = self
+            .interval_seconds
+            .checked_mul(steps)?
+            .checked_add(anchor)?;
+        DateTime::from_timestamp(fire, 0)
+    }
+
+    /// Returns every fire in `[from, until)`, in order.
+    ///
+    /// Fires follow the fixed series of `next_occurrence`, whatever the value
+    /// of `from`. A trigger without a positive interval yields no fires.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_pulse::models::{MilitaryTime, SignalTrigger};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let trigger = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 6 * 3600);
+    /// let from = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let until = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+    /// let hours: Vec<_> = trigger
+    ///     .occurrences(from, until)
+    ///     .map(|fire| fire.format("%H").to_string())
+    ///     .collect();
+    /// assert_eq!(hours, vec!["09", "15", "21"]);
+    /// ```
+    pub fn occurrences(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> impl Iterator<Item = DateTime<Utc>> {
+        let trigger = self.clone();
+        let before = from.checked_sub_signed(Duration::nanoseconds(1));
+        let first = self.next_occurrence(before.unwrap_or(from));
+        std::iter::successors(first, move |fire| trigger.next_occurrence(*fire))
+            .take_while(move |fire| *fire < until)
+    }
+}
+
+impl std::fmt::Display for SignalTrigger {
+    /// Formats the trigger as `"MHH:MM:SS::Ii64"`, the format read by `from_str`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "M{:02}:{:02}:{:02}::I{}",
+            self.time.hour, self.time.minute, self.time.seconds, self.interval_seconds
+        )
+    }
+}
+
+/// Serializes as the compact `"M16:30:25::I86400"` string form.
+#[cfg(feature = "serde")]
+impl serde::Serialize for SignalTrigger {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SignalTrigger {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        SignalTrigger::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Returns the coarsest polling interval, in seconds, that won't miss any of
+/// the `triggers`: the greatest common divisor of their `interval_seconds`.
+///
+/// Returns 0 for an empty slice or if any interval is not positive.
+///
+/// # Example
+///
+/// ```
+/// use event_pulse::models::{signal::common_poll_interval, MilitaryTime, SignalTrigger};
+///
+/// let triggers = [
+///     SignalTrigger::new(MilitaryTime::new(9, 0, 0), 3600),
+///     SignalTrigger::new(MilitaryTime::new(9, 0, 0), 5400),
+/// ];
+/// assert_eq!(common_poll_interval(&triggers), 1800);
+/// ```
+pub fn common_poll_interval(triggers: &[SignalTrigger]) -> i64 {
+    if triggers.iter().any(|trigger| trigger.interval_seconds <= 0) {
+        return 0;
+    }
+    triggers
+        .iter()
+        .map(|trigger| trigger.interval_seconds)
+        .fold(0, gcd)
+}
+
+/// Returns the greatest common divisor of two non-negative numbers.
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_display_round_trip() {
+        let trigger = SignalTrigger::new(MilitaryTime::new(6, 5, 0), 86400);
+        assert_eq!(trigger.to_string(), "M06:05:00::I86400");
+        assert_eq!(SignalTrigger::from_str(&trigger.to_string()), Ok(trigger));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let trigger = SignalTrigger::from_str("M16:30:25::I86400").unwrap();
+        let json = serde_json::to_string(&trigger).unwrap();
+        assert_eq!(json, r#""M16:30:25::I86400""#);
+        assert_eq!(
+            serde_json::from_str::<SignalTrigger>(&json).unwrap(),
+            trigger
+        );
+        assert!(serde_json::from_str::<SignalTrigger>(r#""M16:30""#).is_err());
+    }
 
     #[test]
     fn test_from_str_valid_input() {
@@ -146,4 +448,196 @@ mod tests {
         let result = SignalTrigger::from_str(input);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_list_valid() {
+        let triggers = SignalTrigger::parse_list("M08:00:00::I86400, M20:30:00::I3600", ',');
+        assert_eq!(
+            triggers,
+            Ok(vec![
+                SignalTrigger::new(MilitaryTime::new(8, 0, 0), 86400),
+                SignalTrigger::new(MilitaryTime::new(20, 30, 0), 3600),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_list_reports_failing_segment() {
+        let err = SignalTrigger::parse_list("M08:00:00::I86400|M20:30:00::Ihourly", '|')
+            .expect_err("second segment has a bad interval");
+        match err {
+            AppError::ParseError(message) => {
+                assert!(message.contains("segment 1"), "{}", message);
+                assert!(message.contains("M20:30:00::Ihourly"), "{}", message);
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_firings_per_day() {
+        let time = MilitaryTime::new(9, 0, 0);
+        assert_eq!(
+            SignalTrigger::new(time.clone(), 3600).firings_per_day(),
+            24.0
+        );
+        assert_eq!(
+            SignalTrigger::new(time.clone(), 86400).firings_per_day(),
+            1.0
+        );
+        assert_eq!(SignalTrigger::new(time, 0).firings_per_day(), 0.0);
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let daily = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 86400);
+        let before = Utc.with_ymd_and_hms(2024, 1, 1, 8, 0, 0).unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        assert_eq!(daily.next_occurrence(before), Some(at));
+        // Strictly after: a fire at `after` is skipped.
+        assert_eq!(
+            daily.next_occurrence(at),
+            Some(Utc.with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap())
+        );
+
+        // Sub-day intervals repeat from 1970-01-01 09:00, not from each day.
+        let every_seven_hours = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 7 * 3600);
+        assert_eq!(
+            every_seven_hours.next_occurrence(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2024, 1, 1, 4, 0, 0).unwrap())
+        );
+        assert_eq!(
+            every_seven_hours.next_occurrence(Utc.with_ymd_and_hms(2024, 1, 1, 23, 30, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2024, 1, 2, 1, 0, 0).unwrap())
+        );
+
+        // A weekly trigger fires on the same weekday whichever day it is asked.
+        let weekly = SignalTrigger::new(MilitaryTime::new(9, 0, 0), crate::SECS_IN_WEEK);
+        let thursday = Utc.with_ymd_and_hms(2024, 1, 4, 9, 0, 0).unwrap();
+        for day in 1..=3 {
+            let before = Utc.with_ymd_and_hms(2024, 1, day, 8, 0, 0).unwrap();
+            assert_eq!(weekly.next_occurrence(before), Some(thursday));
+        }
+
+        // A zero interval never fires.
+        let once = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 0);
+        assert_eq!(once.next_occurrence(before), None);
+    }
+
+    #[test]
+    fn test_next_occurrence_chains_like_occurrences() {
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 23, 30, 0).unwrap();
+        let until = Utc.with_ymd_and_hms(2024, 1, 8, 0, 0, 0).unwrap();
+        for interval in [7 * 3600, 86400, crate::SECS_IN_WEEK] {
+            let trigger = SignalTrigger::new(MilitaryTime::new(9, 0, 0), interval);
+            let chained: Vec<_> = std::iter::successors(trigger.next_occurrence(from), |fire| {
+                trigger.next_occurrence(*fire)
+            })
+            .take_while(|fire| *fire < until)
+            .collect();
+            assert_eq!(
+                chained,
+                trigger.occurrences(from, until).collect::<Vec<_>>(),
+                "interval {}",
+                interval
+            );
+            assert!(!chained.is_empty());
+
+            // Starting the window between two fires does not shift the series.
+            let between = chained[0] + Duration::try_seconds(1).unwrap();
+            let later: Vec<_> = trigger.occurrences(between, until).collect();
+            assert_eq!(later, chained[1..]);
+        }
+    }
+
+    #[test]
+    fn test_occurrences() {
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let until = Utc.with_ymd_and_hms(2024, 1, 4, 9, 0, 0).unwrap();
+
+        let daily = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 86400);
+        let fires: Vec<_> = daily.occurrences(from, until).collect();
+        assert_eq!(
+            fires,
+            vec![
+                Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 3, 9, 0, 0).unwrap(),
+            ]
+        );
+
+        // `from` itself is included when the trigger fires at it.
+        let at_nine = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        assert_eq!(daily.occurrences(at_nine, until).next(), Some(at_nine));
+
+        let every_ten_hours = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 10 * 3600);
+        assert_eq!(every_ten_hours.occurrences(from, until).count(), 8);
+
+        let once = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 0);
+        assert_eq!(once.occurrences(from, until).count(), 0);
+    }
+
+    #[test]
+    fn test_cmp_by_next_fire() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let in_an_hour = SignalTrigger::new(MilitaryTime::new(13, 0, 0), 86400);
+        let in_a_day = SignalTrigger::new(MilitaryTime::new(12, 0, 0), 86400);
+        let hourly = SignalTrigger::new(MilitaryTime::new(6, 30, 0), 3600);
+        let spent = SignalTrigger::new(MilitaryTime::new(8, 0, 0), 0);
+
+        assert_eq!(in_an_hour.cmp_by_next_fire(&in_a_day, now), Ordering::Less);
+        assert_eq!(
+            in_a_day.cmp_by_next_fire(&in_an_hour, now),
+            Ordering::Greater
+        );
+        // Next hourly fire is 12:30, before 13:00.
+        assert_eq!(hourly.cmp_by_next_fire(&in_an_hour, now), Ordering::Less);
+        assert_eq!(spent.cmp_by_next_fire(&in_a_day, now), Ordering::Greater);
+    }
+
+    #[cfg(feature = "timezone")]
+    #[test]
+    fn test_utc_offset_changes_across_london_dst() {
+        let trigger = SignalTrigger::new(MilitaryTime::new(9, 0, 0), 86400);
+        let tz = chrono_tz::Europe::London;
+
+        // BST starts on 2024-03-31 and ends on 2024-10-27.
+        let from = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 11, 30, 0, 0, 0).unwrap();
+        assert_eq!(
+            trigger.utc_offset_changes(tz, from, to),
+            vec![
+                Utc.with_ymd_and_hms(2024, 3, 31, 8, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 10, 27, 9, 0, 0).unwrap(),
+            ]
+        );
+
+        let from = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        assert!(trigger.utc_offset_changes(tz, from, to).is_empty());
+    }
+
+    #[test]
+    fn test_common_poll_interval() {
+        let time = MilitaryTime::new(9, 0, 0);
+        let triggers = [
+            SignalTrigger::new(time.clone(), 3600),
+            SignalTrigger::new(time.clone(), 5400),
+        ];
+        assert_eq!(common_poll_interval(&triggers), 1800);
+        assert_eq!(common_poll_interval(&triggers[..1]), 3600);
+    }
+
+    #[test]
+    fn test_common_poll_interval_invalid_input() {
+        let time = MilitaryTime::new(9, 0, 0);
+        assert_eq!(common_poll_interval(&[]), 0);
+        assert_eq!(
+            common_poll_interval(&[
+                SignalTrigger::new(time.clone(), 3600),
+                SignalTrigger::new(time, 0),
+            ]),
+            0
+        );
+    }
 }
@@ -1,10 +1,33 @@
 use chrono::NaiveTime;
 use structsy::derive::PersistentEmbedded;
+use thiserror::Error;
 
 use crate::error::AppError;
 
+/// Identifies which component of a military time string failed to parse.
+#[derive(Error, Debug, PartialEq)]
+pub enum TimeParseError {
+    /// The input is not made of three `:`-separated parts.
+    #[error("Invalid military time format")]
+    WrongPartCount,
+    /// The hour is not a number.
+    #[error("Failed to parse military time hour")]
+    BadHour,
+    /// The minute is not a number.
+    #[error("Failed to parse military time minute")]
+    BadMinute,
+    /// The second is not a number.
+    #[error("Failed to parse military time seconds")]
+    BadSecond,
+}
+
 /// 24-Hour clock also known as military time
 #[derive(Debug, Clone, PartialEq, PersistentEmbedded)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "MilitaryTimeFields")
+)]
 pub struct MilitaryTime {
     /// Represents 24-Hour time format (hour, minute, seconds)
     pub hour: u32,
@@ -12,8 +35,30 @@ pub struct MilitaryTime {
     pub seconds: u32,
 }
 
+/// The unchecked fields of a serialized `MilitaryTime`; deserialization goes
+/// through `MilitaryTime::try_new` so out-of-range values are rejected.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MilitaryTimeFields {
+    hour: u32,
+    minute: u32,
+    seconds: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<MilitaryTimeFields> for MilitaryTime {
+    type Error = AppError;
+
+    fn try_from(fields: MilitaryTimeFields) -> Result<Self, AppError> {
+        MilitaryTime::try_new(fields.hour, fields.minute, fields.seconds)
+    }
+}
+
 impl MilitaryTime {
     /// Constructs a new `MilitaryTime`.
+    ///
+    /// The fields are not checked; use `try_new` for untrusted values, as
+    /// `to_naive_time` panics on out-of-range fields.
     pub fn new(hour: u32, minute: u32, seconds: u32) -> Self {
         MilitaryTime {
             hour,
@@ -22,6 +67,23 @@ impl MilitaryTime {
         }
     }
 
+    /// Constructs a new `MilitaryTime`, checking that every field is within
+    /// range (see `validate`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_pulse::models::MilitaryTime;
+    ///
+    /// assert!(MilitaryTime::try_new(23, 59, 59).is_ok());
+    /// assert!(MilitaryTime::try_new(24, 0, 0).is_err());
+    /// ```
+    pub fn try_new(hour: u32, minute: u32, seconds: u32) -> Result<Self, AppError> {
+        let time = MilitaryTime::new(hour, minute, seconds);
+        time.validate()?;
+        Ok(time)
+    }
+
     /// Parses a string representation of military time into a `MilitaryTime` instance.
     ///
     /// The input string should be formatted as "HH:MM:SS", where:
@@ -36,51 +98,89 @@ impl MilitaryTime {
     /// # Returns
     ///
     /// A `Result` containing a `MilitaryTime` instance if parsing is successful,
-    /// or an `AppError` if parsing fails due to invalid format or other errors.
+    /// an `AppError::TimeParseError` naming the component that is not a number,
+    /// or an `AppError::InvalidInputString` for a component out of range (see
+    /// `validate`).
     ///
     /// # Example
     ///
     /// ```
-    /// use event_pulse::models::MilitaryTime;
+    /// use event_pulse::error::AppError;
+    /// use event_pulse::models::time::{MilitaryTime, TimeParseError};
     ///
     /// let military_time = MilitaryTime::from_str("16:30:25");
     /// assert!(military_time.is_ok());
+    /// assert_eq!(
+    ///     MilitaryTime::from_str("16:30"),
+    ///     Err(AppError::TimeParseError(TimeParseError::WrongPartCount))
+    /// );
     /// ```
     pub fn from_str(input: &str) -> Result<MilitaryTime, AppError> {
         let parts: Vec<&str> = input.trim().split(':').collect();
         if parts.len() != 3 {
             tracing::error!("Invalid military time format");
-            return Err(AppError::InvalidInputString(
-                "Invalid military time format".to_string(),
-            ));
+            return Err(TimeParseError::WrongPartCount.into());
         }
 
-        let hour = parts[0].parse().map_err(|_| {
-            tracing::error!("Failed to parse military time hour");
-            AppError::ParseError("Failed to parse military time hour".to_string())
-        })?;
-
-        let minute = parts[1].parse().map_err(|_| {
-            tracing::error!("Failed to parse military time minute");
-            AppError::ParseError("Failed to parse military time minute".to_string())
-        })?;
+        let component = |part: &str, err: TimeParseError| {
+            part.parse::<u32>().map_err(|_| {
+                tracing::error!("{}", err);
+                err
+            })
+        };
+        let hour = component(parts[0], TimeParseError::BadHour)?;
+        let minute = component(parts[1], TimeParseError::BadMinute)?;
+        let seconds = component(parts[2], TimeParseError::BadSecond)?;
 
-        let seconds = parts[2].parse().map_err(|_| {
-            tracing::error!("Failed to parse military time seconds");
-            AppError::ParseError("Failed to parse military time seconds".to_string())
-        })?;
+        MilitaryTime::try_new(hour, minute, seconds)
+    }
 
-        Ok(MilitaryTime {
-            hour,
-            minute,
-            seconds,
-        })
+    /// Checks that every field is within range: hour 0-23, minute 0-59 and
+    /// seconds 0-59.
+    ///
+    /// Fields are public, so values built by hand or read from storage may be
+    /// out of range; call this before `to_naive_time` to avoid its panic.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` for a valid time, or an `AppError::InvalidInputString` naming
+    /// the first out-of-range field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_pulse::models::MilitaryTime;
+    ///
+    /// assert!(MilitaryTime::new(23, 59, 59).validate().is_ok());
+    /// assert!(MilitaryTime::new(12, 60, 0).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), AppError> {
+        let fields = [
+            ("hour", self.hour, 24),
+            ("minute", self.minute, 60),
+            ("seconds", self.seconds, 60),
+        ];
+        for (name, value, limit) in fields {
+            if value >= limit {
+                tracing::error!("Military time {} out of range: {}", name, value);
+                return Err(AppError::InvalidInputString(format!(
+                    "Military time {} out of range: {}",
+                    name, value
+                )));
+            }
+        }
+        Ok(())
     }
 
     /// Converts `MilitaryTime` to `chrono::NaiveTime`
     /// Structsy v0.5 does not currently handle `chrono` support,
     /// therefore we use `MilitaryTime` of type `u32`
     /// as our concrete type and use `to_naive_time()` to convert into `chrono::NaiveTime`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a field is out of range. Values built with `try_new` or
+    /// `from_str`, or that passed `validate`, always convert.
     pub fn to_naive_time(&self) -> NaiveTime {
         NaiveTime::from_hms_opt(self.hour, self.minute, self.seconds)
             .expect("expected conversion to chrono::NaiveTime")
@@ -149,3 +249,88 @@ pub fn from_duration_to_datetime(
 ) -> chrono::DateTime<chrono::Utc> {
     datetime + duration
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_reports_failing_component() {
+        let error = |input: &str| match MilitaryTime::from_str(input) {
+            Err(AppError::TimeParseError(err)) => err,
+            other => panic!("unexpected result for {:?}: {:?}", input, other),
+        };
+        assert_eq!(error("9a:00:00"), TimeParseError::BadHour);
+        assert_eq!(error("00:-1:00"), TimeParseError::BadMinute);
+        assert_eq!(error("00:00:6O"), TimeParseError::BadSecond);
+        assert_eq!(error("00:00"), TimeParseError::WrongPartCount);
+        assert_eq!(
+            MilitaryTime::from_str("9a:00:00").unwrap_err().to_string(),
+            "Parse error: Failed to parse military time hour"
+        );
+    }
+
+    #[test]
+    fn test_from_str_rejects_out_of_range_components() {
+        assert_eq!(
+            MilitaryTime::from_str("24:00:00"),
+            Err(AppError::InvalidInputString(
+                "Military time hour out of range: 24".to_string()
+            ))
+        );
+        assert_eq!(
+            MilitaryTime::from_str("12:60:00"),
+            Err(AppError::InvalidInputString(
+                "Military time minute out of range: 60".to_string()
+            ))
+        );
+        assert_eq!(
+            MilitaryTime::from_str("00:99:00"),
+            Err(AppError::InvalidInputString(
+                "Military time minute out of range: 99".to_string()
+            ))
+        );
+        assert!(MilitaryTime::from_str("00:00:60").is_err());
+    }
+
+    #[test]
+    fn test_from_str_accepts_boundary() {
+        let time = MilitaryTime::from_str("23:59:59").unwrap();
+        assert_eq!(time, MilitaryTime::new(23, 59, 59));
+        assert_eq!(time.to_naive_time().to_string(), "23:59:59");
+        assert_eq!(MilitaryTime::try_new(23, 59, 59), Ok(time));
+        assert!(MilitaryTime::try_new(0, 75, 0).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let time = MilitaryTime::new(16, 30, 25);
+        let json = serde_json::to_string(&time).unwrap();
+        assert_eq!(json, r#"{"hour":16,"minute":30,"seconds":25}"#);
+        assert_eq!(serde_json::from_str::<MilitaryTime>(&json).unwrap(), time);
+
+        let err = serde_json::from_str::<MilitaryTime>(r#"{"hour":99,"minute":0,"seconds":0}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("hour out of range: 99"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_rejects_hour_24() {
+        assert_eq!(
+            MilitaryTime::new(24, 0, 0).validate(),
+            Err(AppError::InvalidInputString(
+                "Military time hour out of range: 24".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_validate_accepts_in_range_times() {
+        for time in [MilitaryTime::new(0, 0, 0), MilitaryTime::new(23, 59, 59)] {
+            assert_eq!(time.validate(), Ok(()));
+            assert_eq!(MilitaryTime::from_naive_time(time.to_naive_time()), time);
+        }
+        assert!(MilitaryTime::new(0, 0, 60).validate().is_err());
+    }
+}
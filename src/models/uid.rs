@@ -1,6 +1,54 @@
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use std::collections::hash_map::RandomState;
 use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Mutex;
 use thiserror::Error;
 
+use crate::error::AppError;
+
+/// Per-process sequence clock mixed into every GlobalId.
+static SEQUENCE: Lazy<Mutex<SequenceClock>> = Lazy::new(|| Mutex::new(SequenceClock::default()));
+
+/// Hands out the timestamp and sequence number of each GlobalId.
+///
+/// The sequence restarts at a random offset every millisecond, so processes
+/// creating IDs in the same millisecond are unlikely to collide. Each
+/// millisecond has 65,536 sequence numbers; once they are used up no further
+/// IDs are issued until the clock moves on.
+#[derive(Debug, Default)]
+struct SequenceClock {
+    /// The millisecond IDs are currently issued in.
+    millis: u64,
+    /// How many IDs have been issued in `millis`.
+    issued: u32,
+    /// The random sequence number of the first ID in `millis`.
+    offset: u16,
+}
+
+impl SequenceClock {
+    /// Returns the timestamp and sequence number for an ID created at `now`, in
+    /// milliseconds, or `None` if every sequence number of the current
+    /// millisecond is taken.
+    ///
+    /// A clock that steps backwards keeps issuing in the latest millisecond
+    /// seen, so IDs stay unique.
+    fn next(&mut self, now: u64) -> Option<(u64, u16)> {
+        if now > self.millis {
+            self.millis = now;
+            self.issued = 0;
+            self.offset = RandomState::new().build_hasher().finish() as u16;
+        }
+        if self.issued > u32::from(u16::MAX) {
+            return None;
+        }
+        let sequence = self.offset.wrapping_add(self.issued as u16);
+        self.issued += 1;
+        Some((self.millis, sequence))
+    }
+}
+
 /// Error type for Prefix creation failures.
 #[derive(Error, Debug, PartialEq)]
 pub enum PrefixError {
@@ -30,7 +78,8 @@ fn prefix(input: &str) -> Result<[u8; 4], PrefixError> {
     Ok(result)
 }
 
-/// Represents a concatenated ID consisting of a prefix and a timestamp.
+/// Represents a concatenated ID consisting of a prefix, a timestamp and a
+/// sequence number.
 #[derive(Debug)]
 pub struct GlobalId([u8; 12]);
 
@@ -39,27 +88,73 @@ impl GlobalId {
     /// The provided prefix must not exceed 4 bytes in length. Prefix is a way to provide
     /// human readeable context to a global ID, similar to a `tag`.
     ///
+    /// The timestamp is in milliseconds since the Unix epoch. The sequence is a
+    /// per-process counter that starts at a random value each millisecond. A
+    /// process creating more than 65,536 IDs in one millisecond waits for the
+    /// next one, so IDs from one process never collide.
+    ///
     /// ```ignore
-    /// V V V V  W W W W W W W W
-    /// └─────┘ └───────────────┘
-    ///    |           |
-    ///  Prefix    Timestamp
+    /// V V V V  W W W W W W  S S
+    /// └─────┘ └───────────┘ └─┘
+    ///    |          |        |
+    ///  Prefix   Timestamp  Sequence
     /// ```
     pub fn new(pfx: &str) -> [u8; 12] {
         let id_prefix = prefix(pfx).unwrap_or_else(|err| {
             panic!("Failed to generate prefix: {}", err);
         });
-        let timestamp = crate::utils::timestamp();
+        let (timestamp, sequence) = loop {
+            let now = crate::utils::timestamp() / 1000;
+            let next = SEQUENCE
+                .lock()
+                .expect("GlobalId sequence lock poisoned")
+                .next(now);
+            match next {
+                Some(next) => break next,
+                None => std::thread::yield_now(),
+            }
+        };
         let mut global_id = [0; 12];
 
         // Fill the first four elements with the prefix bytes
         global_id[..4].copy_from_slice(&id_prefix);
-        // Fill the next eight elements with the timestamp bytes
-        global_id[4..].copy_from_slice(&timestamp.to_be_bytes());
+        // Fill the next six elements with the low 48 bits of the timestamp
+        global_id[4..10].copy_from_slice(&timestamp.to_be_bytes()[2..]);
+        // Fill the last two elements with the sequence number
+        global_id[10..].copy_from_slice(&sequence.to_be_bytes());
 
         global_id
     }
 
+    /// Parses the `Display` form of a GlobalId, 4 prefix characters followed
+    /// by 16 hexadecimal digits, back into a GlobalId.
+    ///
+    /// `Display` upper-cases the prefix, so the parsed prefix is upper case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_pulse::models::uid::GlobalId;
+    ///
+    /// let id = GlobalId::from_vec(GlobalId::new("EVNT").to_vec());
+    /// let parsed = GlobalId::from_display_str(&id.to_string()).unwrap();
+    /// assert_eq!(parsed.to_vec(), id.to_vec());
+    /// ```
+    pub fn from_display_str(s: &str) -> Result<GlobalId, AppError> {
+        let invalid = || AppError::ParseError(format!("Invalid GlobalId: {:?}", s));
+        if !s.is_ascii() || s.len() != 20 {
+            return Err(invalid());
+        }
+
+        let mut global_id = [0; 12];
+        global_id[..4].copy_from_slice(&s.as_bytes()[..4]);
+        for (index, byte) in global_id[4..].iter_mut().enumerate() {
+            let hex = &s[4 + index * 2..6 + index * 2];
+            *byte = u8::from_str_radix(hex, 16).map_err(|_| invalid())?;
+        }
+        Ok(GlobalId(global_id))
+    }
+
     /// Converts the GlobalID to a Vec<u8>, convenience method to satisfy Structsy, ID type.
     pub fn to_vec(&self) -> Vec<u8> {
         self.0.to_vec()
@@ -85,23 +180,20 @@ impl GlobalId {
         prefix_bytes.iter().map(|&b| b as char).collect()
     }
 
-    /// Returns the timestamp from the GlobalId.
+    /// Returns the timestamp from the GlobalId, in milliseconds since the Unix
+    /// epoch.
     pub fn get_timestamp(&self) -> u64 {
-        // Extract the timestamp bytes from the remaining 8 elements of the array
-        let timestamp_bytes = &self.0[4..];
-        // Ensure that there are exactly 8 bytes for the timestamp
-        assert_eq!(timestamp_bytes.len(), 8);
+        // Extract the timestamp bytes from the six elements after the prefix
+        let mut timestamp_bytes = [0; 8];
+        timestamp_bytes[2..].copy_from_slice(&self.0[4..10]);
         // Convert the bytes to a u64 value using big-endian byte order
-        u64::from_be_bytes([
-            timestamp_bytes[0],
-            timestamp_bytes[1],
-            timestamp_bytes[2],
-            timestamp_bytes[3],
-            timestamp_bytes[4],
-            timestamp_bytes[5],
-            timestamp_bytes[6],
-            timestamp_bytes[7],
-        ])
+        u64::from_be_bytes(timestamp_bytes)
+    }
+
+    /// Returns the creation time embedded in the GlobalId's timestamp.
+    pub fn created_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(self.get_timestamp() as i64)
+            .expect("GlobalId timestamp out of range for DateTime<Utc>")
     }
 }
 
@@ -210,12 +302,63 @@ mod tests {
     #[test]
     fn test_get_timestamp() {
         let mut gid: GlobalId = GlobalId([0; 12]);
-        let timestamp: u64 = 1710778108;
+        let timestamp: u64 = 1710778108123;
         let bytes_conversion = timestamp.to_be_bytes();
         let prefix = prefix("test").unwrap();
         gid.0[..4].copy_from_slice(&prefix);
-        gid.0[4..].copy_from_slice(&bytes_conversion);
+        gid.0[4..10].copy_from_slice(&bytes_conversion[2..]);
+        gid.0[10..].copy_from_slice(&[0xFF, 0xFF]);
+
+        assert_eq!(gid.get_timestamp(), 1710778108123);
+        assert_eq!(gid.created_at().timestamp_millis(), 1710778108123);
+    }
 
-        assert_eq!(gid.get_timestamp(), 1710778108);
+    #[test]
+    fn test_new_ids_are_unique() {
+        let ids: std::collections::HashSet<[u8; 12]> =
+            (0..100_000).map(|_| GlobalId::new("EVNT")).collect();
+        assert_eq!(ids.len(), 100_000);
+    }
+
+    #[test]
+    fn test_sequence_clock_waits_for_next_millisecond() {
+        let mut clock = SequenceClock::default();
+        let sequences: std::collections::HashSet<u16> = (0..=u16::MAX)
+            .map(|_| clock.next(1_000).expect("sequence available").1)
+            .collect();
+        assert_eq!(sequences.len(), 65_536);
+        assert_eq!(clock.next(1_000), None);
+        // A clock stepping backwards does not reopen the exhausted millisecond.
+        assert_eq!(clock.next(999), None);
+        assert_eq!(clock.next(1_001).map(|(millis, _)| millis), Some(1_001));
+    }
+
+    #[test]
+    fn test_from_display_str_round_trip() {
+        let global_id = GlobalId([
+            0x45, 0x56, 0x4E, 0x54, 0x01, 0x8E, 0x4F, 0x3A, 0x9B, 0x00, 0xC0, 0xFF,
+        ]);
+        let display = global_id.to_string();
+        assert_eq!(display, "EVNT018E4F3A9B00C0FF");
+        assert_eq!(GlobalId::from_display_str(&display).unwrap().0, global_id.0);
+        assert_eq!(
+            GlobalId::from_display_str("evnt018e4f3a9b00c0ff")
+                .unwrap()
+                .0,
+            [0x65, 0x76, 0x6E, 0x74, 0x01, 0x8E, 0x4F, 0x3A, 0x9B, 0x00, 0xC0, 0xFF]
+        );
+    }
+
+    #[test]
+    fn test_from_display_str_invalid() {
+        for input in [
+            "",
+            "EVNT018E4F3A9B00C0F",
+            "EVNT018E4F3A9B00C0FF0",
+            "EVNT018E4F3A9B00C0FG",
+            "ÉVN018E4F3A9B00C0FF",
+        ] {
+            assert!(GlobalId::from_display_str(input).is_err(), "{}", input);
+        }
     }
 }
@@ -0,0 +1,118 @@
+use std::path::Path;
+
+use structsy::derive::{queries, Persistent};
+use structsy::{Structsy, StructsyTx};
+
+use crate::error::{AppError, StructsyErrWrapper};
+use crate::models::event::Event;
+use crate::models::notify::{EventNotify, EventNotifyQuery};
+
+/// Persists events and notifications in a Structsy database.
+///
+/// # Examples
+///
+/// ```no_run
+/// use event_pulse::storage::StructsyStore;
+///
+/// let store = StructsyStore::open("event_pulse.db").expect("open store");
+/// let notifies = store.notifies_for_event(b"EVNT").expect("query notifications");
+/// assert!(notifies.is_empty());
+/// let events = store.list_events().expect("list events");
+/// println!("{} stored events", events.len());
+/// ```
+pub struct StructsyStore {
+    db: Structsy,
+}
+
+impl StructsyStore {
+    /// Opens the database file `db_filename` in the local application data
+    /// directory (see `config::init_db_datafilepath`).
+    pub async fn open_default(db_filename: &str) -> Result<Self, AppError> {
+        let path = crate::config::init_db_datafilepath(db_filename).await?;
+        Self::open(path)
+    }
+
+    /// Opens the database at `path`, creating it if needed, and defines the
+    /// persisted types.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, AppError> {
+        let db = Structsy::open(Structsy::config(path.as_ref()).create(true))
+            .map_err(StructsyErrWrapper)?;
+        db.define::<EventNotify>().map_err(StructsyErrWrapper)?;
+        db.define::<StoredEvent>().map_err(StructsyErrWrapper)?;
+        Ok(Self { db })
+    }
+
+    /// Persists an event in its own transaction.
+    pub fn insert_event(&self, event: &Event) -> Result<(), AppError> {
+        let record = StoredEvent {
+            key: event_key(event.id()),
+            event: event.clone(),
+        };
+        let mut tx = self.db.begin().map_err(StructsyErrWrapper)?;
+        tx.insert(&record).map_err(StructsyErrWrapper)?;
+        tx.commit().map_err(StructsyErrWrapper)?;
+        Ok(())
+    }
+
+    /// Returns the stored event with `id`, or `None` if there is none.
+    pub fn get_event(&self, id: &[u8]) -> Result<Option<Event>, AppError> {
+        Ok(self
+            .db
+            .query::<StoredEvent>()
+            .by_key(event_key(id))
+            .into_iter()
+            .next()
+            .map(|(_, record)| record.event))
+    }
+
+    /// Returns every stored event.
+    pub fn list_events(&self) -> Result<Vec<Event>, AppError> {
+        Ok(self
+            .db
+            .query::<StoredEvent>()
+            .into_iter()
+            .map(|(_, record)| record.event)
+            .collect())
+    }
+
+    /// Persists a notification in its own transaction.
+    pub fn save_notify(&self, notify: &EventNotify) -> Result<(), AppError> {
+        let mut tx = self.db.begin().map_err(StructsyErrWrapper)?;
+        tx.insert(notify).map_err(StructsyErrWrapper)?;
+        tx.commit().map_err(StructsyErrWrapper)?;
+        Ok(())
+    }
+
+    /// Returns every stored notification scheduled for the event with
+    /// `event_id`, looked up through the index on its event key.
+    pub fn notifies_for_event(&self, event_id: &[u8]) -> Result<Vec<EventNotify>, AppError> {
+        Ok(self
+            .db
+            .query::<EventNotify>()
+            .by_event_key(event_key(event_id))
+            .into_iter()
+            .map(|(_, notify)| notify)
+            .collect())
+    }
+}
+
+/// The persisted form of an `Event`. `Event` is embedded in `EventNotify`, so it
+/// is stored as a top-level record through this wrapper, indexed by the hex
+/// form of its id.
+#[derive(Debug, Clone, PartialEq, Persistent)]
+struct StoredEvent {
+    #[index(mode = "exclusive")]
+    key: String,
+    event: Event,
+}
+
+#[queries(StoredEvent)]
+trait StoredEventQuery {
+    /// Matches the record stored under `key`.
+    fn by_key(self, key: String) -> Self;
+}
+
+/// Returns the index key of the event with `id`, its bytes in lowercase hex.
+pub(crate) fn event_key(id: &[u8]) -> String {
+    id.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -0,0 +1,103 @@
+use chrono::{TimeZone, Utc};
+use event_pulse::models::decimal::Money;
+use event_pulse::models::event::Event;
+use event_pulse::models::notify::{EventNotify, Recipient, SendFrequency, TransportMethod};
+use event_pulse::models::{CalendarData, Epoch, SignalTrigger};
+use event_pulse::storage::StructsyStore;
+
+fn event(title: &str) -> Event {
+    Event::new(
+        title.to_string(),
+        Money::new(25, 0),
+        Epoch::Week(CalendarData::new(1, 1)),
+        None,
+        SignalTrigger::from_str("M09:00:00::I604800").expect("valid signal trigger"),
+        Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap(),
+    )
+}
+
+fn notify(event: Event, frequency: SendFrequency) -> EventNotify {
+    let recipient = Recipient::new("test@example.com");
+    EventNotify::new(
+        event,
+        TransportMethod::Email(recipient.clone()),
+        frequency,
+        vec![recipient],
+        SignalTrigger::from_str("M08:00:00::I86400").expect("valid signal trigger"),
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+    )
+    .expect("valid recipients")
+}
+
+fn temp_db_path() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+        "event_pulse_storage_{}.db",
+        Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ))
+}
+
+#[test]
+fn test_notifies_for_event() {
+    let path = temp_db_path();
+    let store = StructsyStore::open(&path).expect("open store");
+
+    let gym = event("gym");
+    let rent = event("rent");
+    assert_ne!(gym.id(), rent.id());
+    let reminders = [
+        notify(gym.clone(), SendFrequency::OnTrigger),
+        notify(gym.clone(), SendFrequency::DayPrior),
+        notify(rent.clone(), SendFrequency::Weekly),
+    ];
+    for reminder in &reminders {
+        store.save_notify(reminder).expect("save notification");
+    }
+
+    let found = store
+        .notifies_for_event(gym.id())
+        .expect("query notifications");
+    assert_eq!(found.len(), 2);
+    assert!(found.iter().all(|notify| notify.event_id() == gym.id()));
+    assert!(found.contains(&reminders[0]));
+    assert!(found.contains(&reminders[1]));
+
+    let found = store
+        .notifies_for_event(rent.id())
+        .expect("query notifications");
+    assert_eq!(found, vec![reminders[2].clone()]);
+
+    // Moving a notification to another event re-keys it.
+    let mut moved = notify(gym.clone(), SendFrequency::Daily);
+    moved.set_event(rent.clone());
+    store.save_notify(&moved).expect("save notification");
+    let found = store
+        .notifies_for_event(rent.id())
+        .expect("query notifications");
+    assert_eq!(found.len(), 2);
+    assert!(found.contains(&moved));
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_event_round_trip() {
+    let path = temp_db_path();
+    let store = StructsyStore::open(&path).expect("open store");
+    assert!(store.list_events().expect("list events").is_empty());
+
+    let gym = event("gym");
+    let rent = event("rent");
+    assert_ne!(gym.id(), rent.id());
+    store.insert_event(&gym).expect("insert event");
+    store.insert_event(&rent).expect("insert event");
+
+    let events = store.list_events().expect("list events");
+    assert_eq!(events.len(), 2);
+    assert!(events.contains(&gym));
+    assert!(events.contains(&rent));
+    assert_eq!(store.get_event(rent.id()).expect("get event"), Some(rent));
+    assert_eq!(store.get_event(b"missing").expect("get event"), None);
+
+    let _ = std::fs::remove_file(path);
+}